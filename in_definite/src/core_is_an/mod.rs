@@ -1,30 +1,46 @@
+use std::ops::Range;
+
 use crate::string_helper;
 
+use crate::decision::{Accepted, Decision, Reason};
+use crate::error::ArticleError;
 use crate::options::Options;
-
-use crate::rules;
+use crate::resolver::Resolver;
+use crate::rules::{rule_acronyms, rule_ambiguous, rule_numbers};
 
 // Traits required for the unit test assertions:
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Is {
     An,
     A,
     None,
+    /// Both 'a' and 'an' are accepted. Only returned when asking for the accepted articles, see `in_definite::accepted()`.
+    Either,
 }
 
-pub fn a_or_an_capitalized_to_match(is_an: Is, first_word: &str) -> &'static str {
-    let is_title_case = string_helper::is_title_case(first_word);
+pub fn a_or_an_capitalized_to_match(
+    is_an: Is,
+    word: &str,
+    is_upper_case_matched: bool,
+) -> &'static str {
+    let is_title_case = string_helper::is_title_case(word);
+    let is_upper_case = is_upper_case_matched && string_helper::is_upper_case(word);
 
     match is_an {
         Is::An => {
+            if is_upper_case {
+                return "AN";
+            }
+
             if is_title_case {
                 return "An";
             }
 
-            return "an";
+            "an"
         }
         Is::A => {
-            if is_title_case {
+            // 'A' is both the Title Case and the UPPER case article
+            if is_title_case || is_upper_case {
                 return "A";
             }
 
@@ -34,51 +50,418 @@ pub fn a_or_an_capitalized_to_match(is_an: Is, first_word: &str) -> &'static str
     }
 }
 
-pub fn is_an_options_bool(word: &str, options: &Options) -> bool {
-    let word = string_helper::get_first_word(word);
+pub fn explain(word: &str, resolver: &Resolver, options: &Options) -> Decision {
+    try_explain(word, resolver, options).unwrap_or_else(|error| Decision {
+        is: Is::None,
+        reason: Reason::NoArticle { error },
+        head: String::new(),
+        span: 0..0,
+    })
+}
+
+pub fn accepted(word: &str, resolver: &Resolver, options: &Options) -> Accepted {
+    let decision = explain(word, resolver, options);
+
+    let is_either = match decision.reason {
+        Reason::Override { .. } | Reason::NoArticle { .. } => false,
+        _ => rule_ambiguous::is_ambiguous(&decision.head),
+    };
+
+    Accepted {
+        is: if is_either { Is::Either } else { decision.is },
+        preferred: decision.is,
+    }
+}
+
+// Get 'a' or 'an' to match the given word, without explaining why.
+pub fn get_a_or_an(word: &str, resolver: &Resolver, options: &Options) -> &'static str {
+    let (is_an, span) = try_decide(word, resolver, options).unwrap_or((Is::None, 0..0));
+    let head = &word[span.clone()];
 
-    let word_lower = word.to_lowercase();
+    // an ALL CAPS word that is read as a word has an UPPER case article: 'AN UMBRELLA'
+    let is_upper_case_matched = rule_acronyms::is_read_as_word(head, options);
 
-    if rules::rule_numbers::is_number(word) {
-        return rules::rule_numbers::is_an_for_number(word, options);
+    // a capital letter before a number, like 'F' in 'F-16', is part of a code and not a Title Case word: 'an F-16'
+    let is_letter_of_code = head.chars().count() == 1
+        && word[span.end..]
+            .trim_start_matches('-')
+            .starts_with(|ch: char| ch.is_ascii_digit());
+    if is_letter_of_code {
+        return a_or_an_capitalized_to_match(is_an, "", false);
     }
 
-    let is_an_result = is_naively_an(&word_lower);
+    a_or_an_capitalized_to_match(is_an, head, is_upper_case_matched)
+}
 
-    if rules::rule_acronyms::is_acronym(word) {
-        return rules::rule_acronyms::is_an_for_acronym(word);
+// The article and the span of the head token, as for `try_explain()`, but without building a `Decision`.
+pub fn try_decide(
+    word: &str,
+    resolver: &Resolver,
+    options: &Options,
+) -> Result<(Is, Range<usize>), ArticleError> {
+    let (span, symbol) = find_head(word, resolver)?;
+    let head = &word[span.clone()];
+
+    if let Some(entry) = resolver.dictionary().find(head) {
+        return Ok((entry.is, span));
     }
 
-    if rules::rule_exceptions::is_exception(&word_lower)
-        || rules::rule_exceptions::is_exception_after_strip(&word_lower)
-    {
-        return !is_an_result;
+    if let Some((_, spoken)) = symbol {
+        if let Some(is_an) = rule_numbers::is_an_for_words(spoken, options) {
+            return Ok((to_is(is_an), span));
+        }
     }
 
-    is_an_result
+    let folded = string_helper::fold_accents(head);
+    let is = resolver
+        .pipeline()
+        .apply(&folded, options)
+        .unwrap_or_else(|| to_is(is_naively_an(&folded.to_lowercase())));
+
+    Ok((is, span))
 }
 
-fn is_naively_an(word: &str) -> bool {
-    "aeiou".contains(string_helper::get_first_letter(word))
+pub fn try_explain(
+    word: &str,
+    resolver: &Resolver,
+    options: &Options,
+) -> Result<Decision, ArticleError> {
+    let (span, symbol) = find_head(word, resolver)?;
+    let head = &word[span.clone()];
+
+    if let Some(entry) = resolver.dictionary().find(head) {
+        return Ok(Decision {
+            is: entry.is,
+            reason: Reason::Override {
+                entry: entry.clone(),
+            },
+            head: head.to_string(),
+            span,
+        });
+    }
+
+    if let Some((symbol, spoken)) = symbol {
+        if let Some(is_an) = rule_numbers::is_an_for_words(spoken, options) {
+            return Ok(Decision {
+                is: to_is(is_an),
+                reason: Reason::Spoken {
+                    token: symbol.to_string(),
+                    spoken: spoken.to_string(),
+                },
+                head: head.to_string(),
+                span,
+            });
+        }
+    }
+
+    // 'école' is read like 'ecole'
+    let folded = string_helper::fold_accents(head);
+    let (is, reason) = resolver
+        .pipeline()
+        .explain(&folded, options)
+        .unwrap_or_else(|| {
+            (
+                to_is(is_naively_an(&folded.to_lowercase())),
+                Reason::NaiveVowel,
+            )
+        });
+
+    Ok(Decision {
+        is,
+        reason,
+        head: head.to_string(),
+        span,
+    })
+}
+
+// The span of the head token, and the symbol that it starts with, if the symbol is read out by name: '@mention', 'α', '∞'
+type Head<'r> = (Range<usize>, Option<(char, &'r str)>);
+
+fn find_head<'r>(word: &str, resolver: &'r Resolver) -> Result<Head<'r>, ArticleError> {
+    if word.trim().is_empty() {
+        return Err(ArticleError::Empty);
+    }
+
+    let span = string_helper::get_first_word_span(word, resolver.symbols());
+    let head = &word[span.clone()];
+
+    let symbol = resolver.symbols().find(head);
+    if symbol.is_none() {
+        match head.chars().find(|ch| ch.is_alphanumeric()) {
+            None => return Err(ArticleError::NoHeadToken),
+            Some(ch) if !ch.is_numeric() && !string_helper::is_latin_letter(ch) => {
+                return Err(ArticleError::UnsupportedScript)
+            }
+            _ => {}
+        }
+    }
+
+    Ok((span, symbol))
+}
+
+pub fn is_naively_an(word: &str) -> bool {
+    let folded = string_helper::fold_accents(word);
+
+    string_helper::get_first_letter(&folded).map_or(false, |ch| "aeiou".contains(ch))
+}
+
+pub fn to_is(is_an: bool) -> Is {
+    if is_an {
+        return Is::An;
+    }
+
+    Is::A
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn try_is_an(word: &str, options: &Options) -> Result<Is, ArticleError> {
+        Resolver::new(options.clone()).try_is_an(word)
+    }
+
+    fn explain(word: &str, options: &Options) -> Decision {
+        Resolver::new(options.clone()).explain(word)
+    }
+
+    #[test]
+    fn try_is_an_test() {
+        let options = Options::default();
+
+        assert_eq!(Ok(Is::An), try_is_an("apple", &options));
+        assert_eq!(Ok(Is::A), try_is_an("-pear", &options));
+        assert_eq!(Ok(Is::An), try_is_an(".8", &options));
+        assert_eq!(Ok(Is::An), try_is_an("(umbrella", &options));
+
+        assert_eq!(Err(ArticleError::Empty), try_is_an("", &options));
+        assert_eq!(Err(ArticleError::Empty), try_is_an(" \t\n", &options));
+        assert_eq!(Err(ArticleError::NoHeadToken), try_is_an("...", &options));
+        assert_eq!(Err(ArticleError::NoHeadToken), try_is_an("- ,", &options));
+        assert_eq!(
+            Err(ArticleError::UnsupportedScript),
+            try_is_an("яблоко", &options)
+        );
+        assert_eq!(
+            Err(ArticleError::UnsupportedScript),
+            try_is_an("中文", &options)
+        );
+        // a Greek word, rather than a Greek letter
+        assert_eq!(
+            Err(ArticleError::UnsupportedScript),
+            try_is_an(
+                "\u{03B5}\u{03BB}\u{03BB}\u{03B7}\u{03BD}\u{03B9}\u{03BA}\u{03AC}",
+                &options
+            )
+        );
+        assert_eq!(Ok(Is::An), try_is_an("\u{03B1}-helix", &options));
+    }
+
+    #[test]
+    fn try_decide_matches_try_explain() {
+        let resolver = Resolver::default();
+        let options = Options::with_colloquial();
+        let words = [
+            "apple",
+            "unicorn",
+            "(hour)",
+            "heir's",
+            "FBI",
+            "1800",
+            "'80s",
+            "$8",
+            "-8",
+            "@mention",
+            "\u{03B1}-helix",
+            "\u{00E9}cole",
+            "/etc",
+            "www.example.com",
+            "яблоко",
+            "...",
+            "",
+        ];
+
+        for word in words.iter() {
+            let decided = try_decide(word, &resolver, &options);
+            let explained = try_explain(word, &resolver, &options);
+
+            assert_eq!(
+                explained.map(|decision| (decision.is, decision.span)),
+                decided,
+                "{}",
+                word
+            );
+        }
+    }
+
+    #[test]
+    fn explain_test() {
+        let options = Options::with_colloquial();
+
+        let explain_reason = |word: &str| explain(word, &options).reason;
+
+        assert_eq!(Reason::Number { colloquial: false }, explain_reason("8"));
+        assert_eq!(Reason::Number { colloquial: true }, explain_reason("1800"));
+        assert_eq!(
+            Reason::Ordinal { colloquial: false },
+            explain_reason("18th")
+        );
+        assert_eq!(
+            Reason::Ordinal { colloquial: true },
+            explain_reason("1800th")
+        );
+        assert_eq!(Reason::Number { colloquial: false }, explain_reason("18K"));
+        assert_eq!(Reason::Number { colloquial: false }, explain_reason("$8"));
+        assert_eq!(
+            Reason::Year {
+                spoken: "eighties".to_string()
+            },
+            explain_reason("'80s")
+        );
+        assert_eq!(
+            Reason::Spoken {
+                token: "-".to_string(),
+                spoken: "minus".to_string()
+            },
+            explain_reason("-8")
+        );
+        assert_eq!(
+            Reason::Acronym {
+                irregular_letter: true
+            },
+            explain_reason("FIFA")
+        );
+        assert_eq!(
+            Reason::Acronym {
+                irregular_letter: false
+            },
+            explain_reason("CEO")
+        );
+        assert_eq!(
+            Reason::Exception {
+                entry: "heir".to_string()
+            },
+            explain_reason("Heir")
+        );
+        assert_eq!(
+            Reason::StrippedException {
+                entry: "honor".to_string(),
+                suffix: "ed".to_string()
+            },
+            explain_reason("honored")
+        );
+        assert_eq!(Reason::NaiveVowel, explain_reason("apple"));
+        assert_eq!(
+            Reason::NoArticle {
+                error: ArticleError::Empty
+            },
+            explain_reason("")
+        );
+    }
+
+    #[test]
+    fn accepted_test() {
+        let accepted =
+            |word: &str, options: &Options| Resolver::new(options.clone()).accepted(word);
+
+        let options = Options::default();
+        assert_eq!(
+            Accepted {
+                is: Is::Either,
+                preferred: Is::A
+            },
+            accepted("historic", &options)
+        );
+        assert_eq!(
+            Accepted {
+                is: Is::Either,
+                preferred: Is::An
+            },
+            accepted("herb", &options)
+        );
+        assert_eq!(
+            Accepted {
+                is: Is::A,
+                preferred: Is::A
+            },
+            accepted("hair", &options)
+        );
+        assert_eq!(Is::Either, accepted("URL", &options).is);
+        assert_eq!(Is::Either, accepted("FAQs", &options).is);
+        assert_eq!(Is::A, accepted("UN", &options).is);
+        assert_eq!(Is::None, accepted("", &options).is);
+
+        // years: the preferred article follows the options
+        assert_eq!(
+            Accepted {
+                is: Is::Either,
+                preferred: Is::A
+            },
+            accepted("1800", &options)
+        );
+        assert_eq!(
+            Accepted {
+                is: Is::Either,
+                preferred: Is::An
+            },
+            accepted("1800", &Options::with_colloquial())
+        );
+        assert_eq!(Is::An, accepted("18", &options).is);
+    }
+
+    #[test]
+    fn accepts_test() {
+        let either = Accepted {
+            is: Is::Either,
+            preferred: Is::A,
+        };
+        assert!(either.accepts(Is::A));
+        assert!(either.accepts(Is::An));
+        assert!(!either.accepts(Is::None));
+
+        let only_a = Accepted {
+            is: Is::A,
+            preferred: Is::A,
+        };
+        assert!(only_a.accepts(Is::A));
+        assert!(!only_a.accepts(Is::An));
+    }
+
+    #[test]
+    fn explain_head_test() {
+        let decision = explain("heir's fortune", &Options::default());
+
+        assert_eq!(Is::An, decision.is);
+        assert_eq!("heir", decision.head);
+    }
+
     #[test]
     fn a_or_an_capitalized_to_match_test() {
         // Title case - should match
-        assert_eq!("An", a_or_an_capitalized_to_match(Is::An, "Ugly"));
-        assert_eq!("A", a_or_an_capitalized_to_match(Is::A, "Leopard"));
+        assert_eq!("An", a_or_an_capitalized_to_match(Is::An, "Ugly", false));
+        assert_eq!("A", a_or_an_capitalized_to_match(Is::A, "Leopard", false));
         // lower case - do nothing
-        assert_eq!("an", a_or_an_capitalized_to_match(Is::An, "ugly"));
-        assert_eq!("a", a_or_an_capitalized_to_match(Is::A, "leopard"));
+        assert_eq!("an", a_or_an_capitalized_to_match(Is::An, "ugly", false));
+        assert_eq!("a", a_or_an_capitalized_to_match(Is::A, "leopard", false));
         // MiXed case - do nothing
-        assert_eq!("an", a_or_an_capitalized_to_match(Is::An, "UgLy"));
-        assert_eq!("a", a_or_an_capitalized_to_match(Is::A, "lEoparD"));
+        assert_eq!("an", a_or_an_capitalized_to_match(Is::An, "UgLy", false));
+        assert_eq!("a", a_or_an_capitalized_to_match(Is::A, "lEoparD", false));
         // UPPER case - do nothing (acronym)
-        assert_eq!("an", a_or_an_capitalized_to_match(Is::An, "FIFA"));
-        assert_eq!("a", a_or_an_capitalized_to_match(Is::A, "UN"));
+        assert_eq!("an", a_or_an_capitalized_to_match(Is::An, "FIFA", false));
+        assert_eq!("a", a_or_an_capitalized_to_match(Is::A, "UN", false));
+    }
+
+    #[test]
+    fn a_or_an_capitalized_to_match_upper_case_test() {
+        // UPPER case article - should match
+        assert_eq!("AN", a_or_an_capitalized_to_match(Is::An, "AN", true));
+        assert_eq!("A", a_or_an_capitalized_to_match(Is::A, "AN", true));
+        assert_eq!("AN", a_or_an_capitalized_to_match(Is::An, "UMBRELLA", true));
+        // Title case - should match
+        assert_eq!("An", a_or_an_capitalized_to_match(Is::An, "A", true));
+        assert_eq!("A", a_or_an_capitalized_to_match(Is::A, "An", true));
+        // lower case - do nothing
+        assert_eq!("an", a_or_an_capitalized_to_match(Is::An, "a", true));
+        assert_eq!("a", a_or_an_capitalized_to_match(Is::A, "an", true));
     }
 }
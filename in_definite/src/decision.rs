@@ -0,0 +1,76 @@
+use std::ops::Range;
+
+use crate::core_is_an::Is;
+use crate::dictionary::Entry;
+use crate::error::ArticleError;
+
+/// The article chosen for a word, together with why it was chosen.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decision {
+    /// The chosen article.
+    pub is: Is,
+    /// The rule that decided the article.
+    pub reason: Reason,
+    /// The head token that was examined, for example "heir" for "heir's".
+    pub head: String,
+    /// The byte range of the head token within the word, for example `1..5` for "(hour)".
+    pub span: Range<usize>,
+}
+
+/// The articles accepted for a word. Some words accept both 'a' and 'an', for example 'a historic' or 'an historic'.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Accepted {
+    /// `Is::Either` if both 'a' and 'an' are accepted, else the same as `preferred`.
+    pub is: Is,
+    /// The preferred article, under the current options.
+    pub preferred: Is,
+}
+
+impl Accepted {
+    /// Returns true if the given article is accepted.
+    pub fn accepts(&self, is: Is) -> bool {
+        is != Is::None && (self.is == Is::Either || self.is == is)
+    }
+}
+
+/// The rule that decided the article for a word.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Reason {
+    /// The word matches an entry in the dictionary of the `Resolver`.
+    Override { entry: Entry },
+    /// The word is a number.
+    ///
+    /// If `colloquial` is true, then the number was read out colloquially, for example '1800' as 'eighteen hundred'.
+    Number { colloquial: bool },
+    /// The word is an ordinal number, for example '18th'. The article is chosen by the number, as for `Number`.
+    Ordinal { colloquial: bool },
+    /// The word is a decade or a year with an apostrophe, read out as a year: ''80s' as 'eighties', '1800s' as 'eighteen hundreds' or ''18' as 'eighteen'.
+    ///
+    /// A decade is always read out as a year, even if numbers are not colloquial.
+    Year { spoken: String },
+    /// The word is a Roman numeral with the given value, read out as a number. See `Options::roman_numerals`.
+    RomanNumeral { value: u32 },
+    /// The word is a URL, an e-mail address, a domain name, a file extension or a path, read out as spoken.
+    /// For example: '/etc' as 'slash', 'www.example.com' as 'double-u' or '.com' as 'dot com'.
+    Technical { spoken: String },
+    /// The word is an acronym, so is read out letter by letter.
+    ///
+    /// If `irregular_letter` is true, then the first letter is irregular: a consonant that sounds like a vowel (like 'F' or 'M'), or the vowel 'U' that sounds like 'you'.
+    Acronym { irregular_letter: bool },
+    /// The word is an acronym that is pronounced as a word, for example 'NASA'. See `AcronymMode::Pronounced`.
+    ///
+    /// If `listed` is true, then the acronym is in the built-in list, else it was detected as pronounceable.
+    PronouncedAcronym { listed: bool },
+    /// The word starts with a symbol that is read out as words, for example '-' in '-8' is read out as 'minus'.
+    Spoken { token: String, spoken: String },
+    /// The word is in the list of exceptions, for example 'heir'.
+    Exception { entry: String },
+    /// The word is in the list of exceptions, once a suffix is stripped, for example 'heirs'.
+    StrippedException { entry: String, suffix: String },
+    /// The word was decided by a custom `Rule`, with the given name.
+    Rule { name: String },
+    /// The word follows the general rule: 'an' for a word that starts with a vowel, else 'a'.
+    NaiveVowel,
+    /// No article could be chosen.
+    NoArticle { error: ArticleError },
+}
@@ -0,0 +1,310 @@
+use std::fs;
+use std::path::Path;
+
+use crate::core_is_an::Is;
+use crate::error::DictionaryError;
+
+/// How a dictionary entry is matched against a word.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pattern {
+    /// Matches the whole word, ignoring case. For example 'uber' matches 'Uber'.
+    Word(String),
+    /// Matches any word that starts with the prefix, ignoring case. For example 'honor' matches 'honorary'.
+    Prefix(String),
+    /// Matches the whole word, with exactly the same case. For example 'HMRC' does not match 'hmrc'.
+    CaseSensitive(String),
+}
+
+/// A dictionary entry, that forces 'a' or 'an' for the words it matches.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub pattern: Pattern,
+    pub is: Is,
+}
+
+impl Entry {
+    pub fn word(word: &str, is: Is) -> Entry {
+        Entry {
+            pattern: Pattern::Word(word.to_lowercase()),
+            is,
+        }
+    }
+
+    pub fn prefix(prefix: &str, is: Is) -> Entry {
+        Entry {
+            pattern: Pattern::Prefix(prefix.to_lowercase()),
+            is,
+        }
+    }
+
+    pub fn case_sensitive(token: &str, is: Is) -> Entry {
+        Entry {
+            pattern: Pattern::CaseSensitive(token.to_string()),
+            is,
+        }
+    }
+}
+
+/// A list of entries that override the built-in rules.
+///
+/// When more than one entry matches a word, then a case-sensitive entry wins over a whole word entry,
+/// which wins over a prefix entry. The longest matching prefix wins.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Dictionary {
+    entries: Vec<Entry>,
+}
+
+impl Dictionary {
+    pub fn new() -> Dictionary {
+        Dictionary::default()
+    }
+
+    pub fn with_entries(entries: Vec<Entry>) -> Dictionary {
+        Dictionary { entries }
+    }
+
+    /// Parse a dictionary from text, with one entry per line.
+    ///
+    /// Each line has a pattern then an article ('a' or 'an'). The pattern is one of:
+    /// - a word, matched ignoring case: `herbalist a`
+    /// - a prefix, ending with '*': `honor* an`
+    /// - a case-sensitive token, in double quotes: `"HMRC" an`
+    ///
+    /// Blank lines are ignored, and '#' starts a comment that runs to the end of the line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use in_definite::{Dictionary, Options, Resolver};
+    ///
+    /// let dictionary = Dictionary::parse("# brands\n\"HMRC\" an\nuber a # 'you-ber'\n").unwrap();
+    /// let resolver = Resolver::with_dictionary(Options::default(), dictionary);
+    ///
+    /// assert_eq!("A", resolver.get_a_or_an("Uber driver"));
+    /// ```
+    pub fn parse(text: &str) -> Result<Dictionary, DictionaryError> {
+        let mut dictionary = Dictionary::new();
+
+        for (index, line) in text.lines().enumerate() {
+            if let Some(entry) = parse_line(line).map_err(|message| DictionaryError::Parse {
+                line: index + 1,
+                message,
+            })? {
+                dictionary.add(entry);
+            }
+        }
+
+        Ok(dictionary)
+    }
+
+    /// Load a dictionary from a file. See `parse()` for the format.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Dictionary, DictionaryError> {
+        let text = fs::read_to_string(path)?;
+
+        Dictionary::parse(&text)
+    }
+
+    pub fn add(&mut self, entry: Entry) {
+        self.entries.push(entry);
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Find the entry that matches the given head token, if any.
+    pub fn find(&self, head: &str) -> Option<&Entry> {
+        if self.entries.is_empty() {
+            return None;
+        }
+
+        let head_lower = head.to_lowercase();
+
+        let case_sensitive = self.entries.iter().find(|entry| match &entry.pattern {
+            Pattern::CaseSensitive(token) => token == head,
+            _ => false,
+        });
+
+        let word = || {
+            self.entries.iter().find(|entry| match &entry.pattern {
+                Pattern::Word(word) => *word == head_lower,
+                _ => false,
+            })
+        };
+
+        let prefix = || {
+            self.entries
+                .iter()
+                .filter(|entry| match &entry.pattern {
+                    Pattern::Prefix(prefix) => head_lower.starts_with(prefix.as_str()),
+                    _ => false,
+                })
+                .max_by_key(|entry| match &entry.pattern {
+                    Pattern::Prefix(prefix) => prefix.len(),
+                    _ => 0,
+                })
+        };
+
+        case_sensitive.or_else(word).or_else(prefix)
+    }
+}
+
+fn parse_line(line: &str) -> Result<Option<Entry>, String> {
+    let line = strip_comment(line);
+
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    match fields.len() {
+        0 => return Ok(None),
+        2 => {}
+        _ => {
+            return Err(format!(
+                "expected a word then 'a' or 'an', but found '{}'",
+                line.trim()
+            ))
+        }
+    }
+
+    let is = match fields[1].to_lowercase().as_str() {
+        "a" => Is::A,
+        "an" => Is::An,
+        _ => return Err(format!("expected 'a' or 'an', but found '{}'", fields[1])),
+    };
+
+    let pattern = fields[0];
+
+    if pattern.len() > 2 && pattern.starts_with('"') && pattern.ends_with('"') {
+        return Ok(Some(Entry::case_sensitive(
+            &pattern[1..pattern.len() - 1],
+            is,
+        )));
+    }
+
+    if pattern.contains('"') {
+        return Err(format!("unmatched '\"' in '{}'", pattern));
+    }
+
+    if pattern.ends_with('*') {
+        let prefix = &pattern[..pattern.len() - 1];
+        if prefix.is_empty() || prefix.contains('*') {
+            return Err(format!("invalid prefix '{}'", pattern));
+        }
+
+        return Ok(Some(Entry::prefix(prefix, is)));
+    }
+
+    if pattern.contains('*') {
+        return Err(format!(
+            "'*' is only allowed at the end of a prefix, but found '{}'",
+            pattern
+        ));
+    }
+
+    Ok(Some(Entry::word(pattern, is)))
+}
+
+// A comment starts with '#' at the start of the line, or after whitespace.
+fn strip_comment(line: &str) -> &str {
+    let mut previous_is_whitespace = true;
+
+    for (offset, ch) in line.char_indices() {
+        if ch == '#' && previous_is_whitespace {
+            return &line[..offset];
+        }
+
+        previous_is_whitespace = ch.is_whitespace();
+    }
+
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_test() {
+        let dictionary = Dictionary::with_entries(vec![
+            Entry::prefix("hon", Is::A),
+            Entry::prefix("honor", Is::An),
+            Entry::word("Uber", Is::A),
+            Entry::case_sensitive("US", Is::A),
+            Entry::word("us", Is::An),
+        ]);
+
+        assert_eq!(Some(Is::A), dictionary.find("uber").map(|entry| entry.is));
+        assert_eq!(Some(Is::A), dictionary.find("UBER").map(|entry| entry.is));
+        assert_eq!(
+            Some(Is::An),
+            dictionary.find("honorary").map(|entry| entry.is)
+        );
+        assert_eq!(Some(Is::A), dictionary.find("honk").map(|entry| entry.is));
+        assert_eq!(Some(Is::A), dictionary.find("US").map(|entry| entry.is));
+        assert_eq!(Some(Is::An), dictionary.find("Us").map(|entry| entry.is));
+        assert_eq!(None, dictionary.find("ho"));
+    }
+
+    #[test]
+    fn parse_test() {
+        let text = "
+# Brands and jargon
+\"HMRC\" an
+uber     a   # 'you-ber'
+honor*  AN
+
+herbalist\ta
+#hashtag a
+";
+        let dictionary = Dictionary::parse(text).unwrap();
+
+        assert_eq!(
+            &[
+                Entry::case_sensitive("HMRC", Is::An),
+                Entry::word("uber", Is::A),
+                Entry::prefix("honor", Is::An),
+                Entry::word("herbalist", Is::A),
+            ],
+            dictionary.entries()
+        );
+    }
+
+    #[test]
+    fn parse_errors_test() {
+        let line_of_error = |text: &str| match Dictionary::parse(text) {
+            Err(DictionaryError::Parse { line, .. }) => line,
+            _ => 0,
+        };
+
+        assert_eq!(2, line_of_error("uber a\nherbalist"));
+        assert_eq!(1, line_of_error("herbalist the"));
+        assert_eq!(3, line_of_error("\n\nherbal ist a"));
+        assert_eq!(1, line_of_error("* a"));
+        assert_eq!(1, line_of_error("ho*nor an"));
+        assert_eq!(1, line_of_error("\"HMRC an"));
+    }
+
+    #[test]
+    fn parse_error_message_test() {
+        let error = Dictionary::parse("uber a\nherbalist the").unwrap_err();
+
+        assert_eq!(
+            "line 2: expected 'a' or 'an', but found 'the'",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn from_file_missing_test() {
+        match Dictionary::from_file("does/not/exist.txt") {
+            Err(DictionaryError::Io(_)) => {}
+            _ => panic!("expected an IO error"),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// The reason why no article could be chosen for the given word.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArticleError {
+    /// The word is empty, or is all whitespace.
+    Empty,
+    /// The word does not start with a letter or a number, for example "...".
+    NoHeadToken,
+    /// The word starts with a letter from a script that is not supported, for example Cyrillic.
+    UnsupportedScript,
+}
+
+impl fmt::Display for ArticleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self {
+            ArticleError::Empty => "the word is empty",
+            ArticleError::NoHeadToken => "the word does not start with a letter or a number",
+            ArticleError::UnsupportedScript => "the word is not written in a supported script",
+        };
+
+        write!(f, "{}", message)
+    }
+}
+
+impl Error for ArticleError {}
+
+/// The reason why a dictionary could not be loaded.
+#[derive(Debug)]
+pub enum DictionaryError {
+    /// The file could not be read.
+    Io(io::Error),
+    /// A line of the dictionary could not be parsed. The line number starts at 1.
+    Parse { line: usize, message: String },
+}
+
+impl fmt::Display for DictionaryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DictionaryError::Io(error) => write!(f, "could not read the dictionary: {}", error),
+            DictionaryError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl Error for DictionaryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DictionaryError::Io(error) => Some(error),
+            DictionaryError::Parse { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for DictionaryError {
+    fn from(error: io::Error) -> DictionaryError {
+        DictionaryError::Io(error)
+    }
+}
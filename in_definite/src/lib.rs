@@ -3,14 +3,41 @@
 //! Get the indefinite article ('a' or 'an') to match the given word. For example: an umbrella, a user.
 
 mod core_is_an;
+mod decision;
+mod dictionary;
+mod error;
+mod number_words;
 mod options;
+mod resolver;
 mod rules;
+mod symbols;
+mod text;
 mod utils;
 
+use std::borrow::Cow;
+
 use utils::string_helper;
 
 pub use core_is_an::Is;
-pub use options::Options;
+pub use decision::{Accepted, Decision, Reason};
+pub use dictionary::{Dictionary, Entry, Pattern};
+pub use error::{ArticleError, DictionaryError};
+pub use options::{AcronymMode, AllCapsMode, Dialect, ExtensionStyle, NumberLocale, Options};
+pub use resolver::Resolver;
+pub use rules::pipeline::Pipeline;
+pub use rules::rule_acronyms::AcronymRule;
+pub use rules::rule_exceptions::ExceptionRule;
+pub use rules::rule_numbers::NumberRule;
+pub use rules::rule_roman_numerals::RomanNumeralRule;
+pub use rules::rule_technical::TechnicalRule;
+pub use rules::Rule;
+pub use symbols::Symbols;
+pub use text::Finding;
+
+thread_local! {
+    // The free functions share a resolver with the built-in rules, rather than building one for every call.
+    static DEFAULT_RESOLVER: Resolver = Resolver::default();
+}
 
 /// Get 'a' or 'an' to match the given word.
 ///
@@ -68,13 +95,7 @@ pub fn get_a_or_an(word: &str) -> &str {
 /// assert_eq!("An", result);
 /// ```
 pub fn get_a_or_an_options(word: &str, options: &Options) -> &'static str {
-    if word.trim().len() == 0 {
-        return "";
-    }
-
-    let is_an = is_an_options(word, options);
-
-    core_is_an::a_or_an_capitalized_to_match(is_an, string_helper::get_first_word(word))
+    DEFAULT_RESOLVER.with(|resolver| core_is_an::get_a_or_an(word, resolver, options))
 }
 
 /// Returns true if the given word should be used with 'an' (not 'a').
@@ -128,15 +149,194 @@ pub fn is_an(word: &str) -> Is {
 /// assert_eq!(in_definite::Is::None, result);
 /// ```
 pub fn is_an_options(word: &str, options: &Options) -> Is {
-    if word.trim().len() == 0 {
-        return Is::None;
-    }
+    DEFAULT_RESOLVER.with(|resolver| {
+        core_is_an::try_decide(word, resolver, options)
+            .map(|(is, _)| is)
+            .unwrap_or(Is::None)
+    })
+}
 
-    if core_is_an::is_an_options_bool(word, options) {
-        return Is::An;
-    }
+/// Returns whether the given word should be used with 'an' or 'a', or the reason why neither could be chosen.
+///
+/// Unlike `is_an_options()`, this tells apart the different kinds of input that have no article.
+///
+/// # Examples
+///
+/// ```
+/// use in_definite;
+///
+/// let result = in_definite::try_is_an("alien", &in_definite::Options::default());
+///
+/// assert_eq!(Ok(in_definite::Is::An), result);
+/// ```
+///
+/// ```
+/// let result = in_definite::try_is_an(" ", &in_definite::Options::default());
+///
+/// assert_eq!(Err(in_definite::ArticleError::Empty), result);
+/// ```
+///
+/// ```
+/// let result = in_definite::try_is_an("...", &in_definite::Options::default());
+///
+/// assert_eq!(Err(in_definite::ArticleError::NoHeadToken), result);
+/// ```
+pub fn try_is_an(word: &str, options: &Options) -> Result<Is, ArticleError> {
+    DEFAULT_RESOLVER
+        .with(|resolver| core_is_an::try_decide(word, resolver, options).map(|(is, _)| is))
+}
 
-    Is::A
+/// Explain which article to use for the given word, and which rule decided it.
+///
+/// # Examples
+///
+/// ```
+/// use in_definite::{Is, Options, Reason};
+///
+/// let decision = in_definite::explain("heirs", &Options::default());
+///
+/// assert_eq!(Is::An, decision.is);
+/// assert_eq!("heirs", decision.head);
+/// assert_eq!(
+///     Reason::StrippedException { entry: "heir".to_string(), suffix: "s".to_string() },
+///     decision.reason
+/// );
+/// ```
+///
+/// ```
+/// use in_definite::Options;
+///
+/// // the head token, without the quotes and markup around it
+/// let decision = in_definite::explain("\"*hour*\"", &Options::default());
+///
+/// assert_eq!("hour", decision.head);
+/// assert_eq!(2..6, decision.span);
+/// ```
+///
+/// ```
+/// use in_definite::{Is, Options, Reason};
+///
+/// let decision = in_definite::explain("1800", &Options::with_colloquial());
+///
+/// assert_eq!(Is::An, decision.is);
+/// assert_eq!(Reason::Number { colloquial: true }, decision.reason);
+/// ```
+pub fn explain(word: &str, options: &Options) -> Decision {
+    DEFAULT_RESOLVER.with(|resolver| core_is_an::explain(word, resolver, options))
+}
+
+/// Get the articles accepted for the given word: both 'a' and 'an' are accepted for some words.
+///
+/// If both are accepted, then `is` is `Is::Either`. The preferred article, under the given options, is always given.
+///
+/// # Examples
+///
+/// ```
+/// use in_definite::{Accepted, Is, Options};
+///
+/// // 'a historic' or 'an historic'
+/// let result = in_definite::accepted("historic", &Options::default());
+///
+/// assert_eq!(Accepted { is: Is::Either, preferred: Is::A }, result);
+/// assert!(result.accepts(Is::An));
+/// ```
+///
+/// ```
+/// use in_definite::{Accepted, Is, Options};
+///
+/// let result = in_definite::accepted("umbrella", &Options::default());
+///
+/// assert_eq!(Accepted { is: Is::An, preferred: Is::An }, result);
+/// assert!(!result.accepts(Is::A));
+/// ```
+pub fn accepted(word: &str, options: &Options) -> Accepted {
+    DEFAULT_RESOLVER.with(|resolver| core_is_an::accepted(word, resolver, options))
+}
+
+/// Scan the given text, and report every 'a' or 'an' that does not match the word following it.
+///
+/// Where both articles are accepted (see `accepted()`), neither is reported.
+///
+/// # Examples
+///
+/// ```
+/// use in_definite;
+///
+/// let findings = in_definite::check_text("An umbrella and\na apple.", &in_definite::Options::default());
+///
+/// assert_eq!(1, findings.len());
+/// assert_eq!(16..17, findings[0].span);
+/// assert_eq!((2, 1), (findings[0].line, findings[0].column));
+/// assert_eq!("a", findings[0].found);
+/// assert_eq!("an", findings[0].expected);
+/// ```
+pub fn check_text(text: &str, options: &Options) -> Vec<Finding> {
+    DEFAULT_RESOLVER.with(|resolver| text::check_text(text, resolver, options))
+}
+
+/// Fix every 'a' or 'an' in the given text that does not match the word following it, keeping the original case.
+///
+/// If nothing needs fixing, then the text is returned unchanged (borrowed).
+///
+/// # Examples
+///
+/// ```
+/// use in_definite;
+///
+/// let result = in_definite::fix_text("A apple and a umbrella", &in_definite::Options::default());
+///
+/// assert_eq!("An apple and an umbrella", result);
+/// ```
+///
+/// ```
+/// // UPPER case
+/// let result = in_definite::fix_text("AN UNICORN", &in_definite::Options::default());
+///
+/// assert_eq!("A UNICORN", result);
+/// ```
+pub fn fix_text<'t>(text: &'t str, options: &Options) -> Cow<'t, str> {
+    DEFAULT_RESOLVER.with(|resolver| text::fix_text(text, resolver, options))
+}
+
+/// Spell out a number as it is read out: integers, decimals and numbers with thousands separators,
+/// ordinals, fractions, ranges, clock times, numbers with a unit and amounts of money.
+///
+/// With colloquial numbers, a 4 digit number is read out as a year. If the text is not a number, then an empty string is returned.
+///
+/// A leading zero is read as 'oh' when more digits or a radix prefix follow it: '007' is 'oh oh seven' and '0x8F' is 'oh ex eight eff'.
+/// A zero on its own, or before a decimal mark, is read as 'zero': '0' and '0.5'.
+///
+/// # Examples
+///
+/// ```
+/// use in_definite::Options;
+///
+/// assert_eq!("eighty million", in_definite::number_to_words("80,000,000", &Options::default()));
+/// assert_eq!("one point eight", in_definite::number_to_words("1.8", &Options::default()));
+/// assert_eq!("one thousand eight hundred", in_definite::number_to_words("1800", &Options::default()));
+/// assert_eq!("eighteen hundred", in_definite::number_to_words("1800", &Options::with_colloquial()));
+/// ```
+///
+/// ```
+/// use in_definite::Options;
+///
+/// assert_eq!("three eighths", in_definite::number_to_words("3/8", &Options::default()));
+/// assert_eq!("eight to ten", in_definite::number_to_words("8\u{2013}10", &Options::default()));
+/// assert_eq!("eleven thirty", in_definite::number_to_words("11:30", &Options::default()));
+/// assert_eq!("eight gigabytes", in_definite::number_to_words("8GB", &Options::default()));
+/// assert_eq!("eight dollars", in_definite::number_to_words("$8", &Options::default()));
+/// assert_eq!("oh oh seven", in_definite::number_to_words("007", &Options::default()));
+/// ```
+///
+/// ```
+/// use in_definite::{NumberLocale, Options};
+///
+/// let options = Options::with_number_locale(NumberLocale::De);
+///
+/// assert_eq!("eighteen thousand point five", in_definite::number_to_words("18.000,5", &options));
+/// ```
+pub fn number_to_words(number: &str, options: &Options) -> String {
+    number_words::number_to_words(number, options)
 }
 
 #[cfg(test)]
@@ -166,6 +366,65 @@ mod tests {
         assert_eq!(Is::None, is_an("   "));
     }
 
+    #[test]
+    fn no_panic_on_unusual_input() {
+        let inputs = [
+            ".",
+            "-",
+            "'",
+            "...",
+            "- ,",
+            ".8",
+            "-apple",
+            "'80s",
+            "(umbrella",
+            "—",
+            "яблоко",
+            "😀",
+            "\u{0301}",
+            "\u{0}",
+            "\u{200B}",
+            "8-",
+            "11.",
+            "'s",
+            "'",
+            "'80",
+            "'s80",
+            "''8",
+            "\u{FEFF}a",
+            ":\u{2013}",
+            "8:1\u{20AC} fee",
+            "8:\u{00BD}",
+            "://",
+            "a://",
+            "@.com",
+            "x@",
+            ".",
+            "./",
+            "~/",
+            "C:\\",
+            "\\",
+            ".\u{00E9}xe",
+        ];
+
+        for input in inputs.iter() {
+            get_a_or_an(input);
+            is_an(input);
+            let _ = try_is_an(input, &Options::with_colloquial());
+            number_to_words(input, &Options::default());
+            fix_text(&format!("a {} fee", input), &Options::default());
+        }
+    }
+
+    #[test]
+    fn leading_punctuation() {
+        assert_eq!("an", get_a_or_an("-apple"));
+        assert_eq!("an", get_a_or_an(".8"));
+        assert_eq!("an", get_a_or_an("(umbrella"));
+        assert_eq!("", get_a_or_an("..."));
+        assert_eq!(Is::None, is_an("..."));
+    }
+
     macro_rules! tests {
         ($($name:ident: $value:expr,)*) => {
         $(
@@ -207,6 +466,40 @@ mod tests {
         test_ac6: ("UN", "a"),
         test_ac7: ("US", "a"),
         test_ac8: ("USA", "a"),
+        // acronyms - with digits
+        test_ac_d1: ("MP3", "an"),
+        test_ac_d2: ("HTML5", "an"),
+        test_ac_d3: ("F-16", "an"),
+        test_ac_d4: ("F16", "an"),
+        test_ac_d5: ("B2B", "a"),
+        test_ac_d6: ("4K", "a"),
+        test_ac_d7: ("8K", "an"),
+        test_ac_d8: ("18K", "an"),
+        test_ac_d9: ("1080p", "a"),
+        test_ac_d10: ("3D", "a"),
+        // single letters - a capital letter is Title Case, unless it is part of a code like 'F-16'
+        test_ac_l1: ("A", "An"),
+        test_ac_l2: ("F", "An"),
+        test_ac_l3: ("U", "A"),
+        test_ac_l4: ("X-ray", "An"),
+        test_ac_l5: ("U.S.", "A"),
+        test_ac_l6: ("M.D.", "An"),
+        test_ac_l7: ("X-11", "an"),
+        // acronyms - mixed case
+        test_ac_mc1: ("mRNA", "an"),
+        test_ac_mc2: ("pH", "a"),
+        test_ac_mc3: ("xUnit", "an"),
+        test_ac_mc4: ("iOS", "an"),
+        test_ac_mc5: ("uBlock", "a"),
+        test_ac_mc6: ("eBay", "an"),
+        test_ac_mc7: ("HTMLElement", "an"),
+        test_ac_mc8: ("XMLHttpRequest", "an"),
+        test_ac_mc9: ("URLSearchParams", "a"),
+        test_ac_mc10: ("OAuth", "an"),
+        test_ac_mc11: ("YouTube", "a"), // mixed case
+        // acronyms - plural
+        test_ac_p1: ("PDFs", "a"),
+        test_ac_p2: ("NFTs", "an"),
         // words
         test_a1: ("alien", "an"),
         test_a2a: ("american", "an"),
@@ -240,6 +533,8 @@ mod tests {
         test_h1: ("hair", "a"),
         test_h2: ("heir", "an"),
         test_h3: ("herb", "an"), // USA not UK
+        test_h3b: ("herbal", "an"),
+        test_h3c: ("herbs", "an"),
         test_h4: ("hotel", "a"),
         test_o0: ("ordinary", "an"),
         test_o1: ("ouija", "a"),
@@ -355,6 +650,149 @@ mod tests {
         test_ns18_13: ("180000000000000", "a"),
         test_ns18_14: ("1800000000000000", "a"),
         test_ns18_15: ("18000000000000000", "an"),
+        // separators and decimals
+        test_nsep1: ("80,000,000", "an"),
+        test_nsep2: ("18,000", "an"),
+        test_nsep3: ("180,000", "a"),
+        test_nsep4: ("1,800,000", "a"),
+        test_nsep5: ("11,000,000", "an"),
+        test_ndec1: ("1.8", "a"),
+        test_ndec2: ("8.1", "an"),
+        test_ndec3: ("11.5", "an"),
+        test_ndec4: ("0.8", "a"),
+        test_ndec5: ("18.5%", "an"),
+        // decades, centuries and years
+        test_year1: ("'80s", "an"),
+        test_year2: ("\u{2019}80s", "an"),
+        test_year3: ("'18 vintage", "an"),
+        test_year4: ("'90s", "a"),
+        test_year5: ("1800s", "an"), // 'eighteen hundreds'
+        test_year6: ("1100s", "an"),
+        test_year7: ("1980s", "a"),
+        test_year8: ("80s", "an"),
+        test_year9: ("11th century", "an"),
+        test_year10: ("18th-century", "an"),
+        test_year11: ("1000s", "a"), // 'thousands'
+        test_year12: ("'8'", "an"), // quoted, not a year
+        // fractions, ranges, times and units
+        test_frac1: ("1/8", "a"),
+        test_frac2: ("8/10", "an"),
+        test_frac3: ("\u{215B}", "a"),
+        test_frac4: ("8\u{00BD}", "an"),
+        test_range1: ("8\u{2013}10 minute wait", "an"),
+        test_range2: ("8-to-10 minute wait", "an"),
+        test_range3: ("11-18", "an"),
+        test_range4: ("10-18", "a"),
+        test_time1: ("8:30", "an"),
+        test_time2: ("11:30 meeting", "an"),
+        test_time3: ("11 pm", "an"),
+        test_time4: ("1:30", "a"),
+        test_unit1: ("8kg", "an"),
+        test_unit2: ("11km", "an"),
+        test_unit3: ("18GB", "an"),
+        test_unit4: ("1TB", "a"),
+        test_unit5: ("8GB card", "an"),
+        // hex, binary, scientific notation and non-ASCII digits
+        test_hex1: ("0x8F", "an"),
+        test_hex2: ("0b1010", "an"),
+        // a leading zero is read as 'oh', like the '0' of '0x8F', unless it is on its own
+        test_hex3: ("007", "an"),
+        test_hex4: ("0123", "an"),
+        test_hex5: ("0", "a"),
+        test_hex6: ("0.5", "a"),
+        test_sci1: ("8e10", "an"),
+        test_sci2: ("1.8\u{00D7}10\u{00B3}", "a"),
+        test_sci3: ("1e-5", "a"),
+        test_digits1: ("\u{FF18}", "an"), // full-width 8
+        test_digits2: ("\u{FF11}\u{FF18}", "an"),
+        test_digits3: ("\u{0668}", "an"), // Arabic-Indic 8
+        test_digits4: ("\u{0661}\u{0660}", "a"),
+        test_digits5: ("\u{06F8}\u{06F0}", "an"), // Extended Arabic-Indic 80
+        // quotes, brackets, markup and whitespace around the head token
+        test_head1: ("\"umbrella\"", "an"),
+        test_head2: ("\u{201C}umbrella\u{201D}", "an"),
+        test_head3: ("(apple)", "an"),
+        test_head4: ("[apple]", "an"),
+        test_head5: ("*hour*", "an"),
+        test_head6: ("_hour_", "an"),
+        test_head7: ("`hour`", "an"),
+        test_head8: ("<b>hour</b>", "an"),
+        test_head9: ("\t\nhour", "an"),
+        test_head10: ("\u{00A0}hour", "an"),
+        test_head11: ("\u{200B}hour\u{200B}", "an"),
+        test_head12: ("**heir's**", "an"),
+        // accented letters and ligatures
+        test_accent1: ("\u{00E9}lan", "an"),
+        test_accent2: ("\u{00E9}cole", "an"),
+        test_accent3: ("\u{00FC}ber", "an"),
+        test_accent4: ("\u{00C5}ngstr\u{00F6}m", "An"),
+        test_accent5: ("\u{0153}uvre", "an"),
+        test_accent6: ("\u{00E6}sthetic", "an"),
+        test_accent7: ("e\u{0301}lan", "an"), // decomposed (NFD)
+        test_accent8: ("A\u{030A}ngstro\u{0308}m", "An"),
+        test_accent9: ("\u{00E0} la carte", "an"),
+        test_accent10: ("\u{00E7}a", "a"),
+        test_accent11: ("\u{00EE}le", "an"),
+        // silent h, from French
+        test_loan1: ("hors d'oeuvre", "an"),
+        test_loan2: ("hors-d'\u{0153}uvre", "an"),
+        test_loan3: ("hauteur", "an"),
+        test_loan4: ("habitu\u{00E9}", "an"),
+        test_loan5: ("habitue\u{0301}s", "an"),
+        test_loan6: ("hommage", "an"),
+        // Greek letters and mathematical symbols
+        test_greek1: ("\u{03B1}-helix", "an"),
+        test_greek2: ("\u{03B5}-neighbourhood", "an"),
+        test_greek3: ("\u{03B2}-test", "a"),
+        test_greek4: ("\u{03A9} resistor", "An"),
+        test_greek5: ("\u{03B7}", "an"),
+        test_greek6: ("\u{03BC}m", "a"),
+        test_greek7: ("\u{03C5}", "an"),
+        test_greek8: ("(\u{03C0})", "a"),
+        test_math1: ("\u{221E} loop", "an"),
+        test_math2: ("\u{222B}", "an"),
+        test_math3: ("\u{2211}", "a"),
+        test_math4: ("\u{2205}", "an"),
+        test_math5: ("\u{222A}", "a"),
+        // symbols and emoji
+        test_sym1: ("@mention", "an"),
+        test_sym2: ("#hashtag", "a"),
+        test_sym3: ("&amp;", "an"),
+        test_sym4: ("+1", "a"),
+        test_sym5: ("%", "a"),
+        test_sym6: ("(@admin)", "an"),
+        test_emoji1: ("\u{1F34E}", "an"),
+        test_emoji2: ("\u{1F34A} emoji", "an"),
+        test_emoji3: ("\u{1F418}", "an"),
+        test_emoji4: ("\u{1F984}", "a"),
+        test_emoji5: ("\u{2764}\u{FE0F}", "a"),
+        test_emoji6: ("\u{1F44D}", "a"),
+        // URLs, e-mail addresses, domain names, file extensions and paths
+        test_tech1: ("https://example.com", "an"),
+        test_tech2: ("http://example.com link", "an"),
+        test_tech3: ("ftp://example.com", "an"),
+        test_tech4: ("www.example.com link", "a"),
+        test_tech5: ("example.com", "an"),
+        test_tech6: ("(www.example.com)", "a"),
+        test_tech7: ("x@y.com address", "an"),
+        test_tech8: ("user@example.com", "a"),
+        test_tech9: ("/etc path", "a"),
+        test_tech10: ("~/bin folder", "a"),
+        test_tech11: ("./configure script", "a"),
+        test_tech12: ("C:\\Windows folder", "a"),
+        test_tech13: ("F:\\ drive", "an"),
+        test_tech14: ("\\\\server\\share", "a"),
+        test_tech15: (".exe file", "an"),
+        test_tech16: (".gif", "a"),
+        test_tech17: (".html page", "an"),
+        test_tech18: (".mov file", "a"),
+        test_tech19: (".com domain", "a"),
+        test_tech20: (".NET developer", "a"),
+        test_tech21: ("HTTPS URL", "an"),
+        test_tech22: ("readme.txt", "a"),
+        test_tech23: ("EXAMPLE.COM", "an"),
+        test_tech24: ("Example.Com", "an"),
+        test_tech25: ("USER.COM", "a"),
         // starting with 8
         test_n8_01: ("8", "an"),
         test_n8_02: ("80", "an"),
@@ -365,6 +803,46 @@ mod tests {
         test_n8_07: ("8000000", "an"),
         test_n8_08: ("80000000", "an"),
         test_n8_09: ("800000000", "an"),
+        // ordinals
+        test_ord1: ("1st", "a"),
+        test_ord2: ("2nd", "a"),
+        test_ord3: ("3rd", "a"),
+        test_ord4: ("8th", "an"),
+        test_ord5: ("8th place", "an"),
+        test_ord6: ("11th", "an"),
+        test_ord7: ("11th-hour", "an"),
+        test_ord8: ("18th", "an"),
+        test_ord9: ("18th birthday", "an"),
+        test_ord10: ("80th", "an"),
+        test_ord11: ("88th", "an"),
+        test_ord12: ("100th", "a"),
+        test_ord13: ("111th", "a"),
+        test_ord14: ("800th", "an"),
+        test_ord15: ("1100th", "a"), // 'one thousand one hundredth'
+        test_ord16: ("1800th", "a"),
+        test_ord17: ("18000th", "an"),
+        test_ord18: ("8TH", "an"),
+        test_ord19: ("11st", "an"),
+        // currency, percent and signs
+        test_cur1: ("$8", "an"),
+        test_cur2: ("$8 fee", "an"),
+        test_cur3: ("$5", "a"),
+        test_cur4: ("€11", "an"),
+        test_cur5: ("£18,000", "an"),
+        test_cur6: ("¥1", "a"),
+        test_cur7: ("₹80", "an"),
+        test_cur8: ("11-euro", "an"),
+        test_pc1: ("8%", "an"),
+        test_pc2: ("5%", "a"),
+        test_pc3: ("18% rise", "an"),
+        test_sign1: ("-8", "a"),
+        test_sign2: ("+11", "a"),
+        test_sign3: ("\u{2212}8", "a"),
+        test_sign4: ("±8", "a"),
+        test_sign5: ("-$8", "a"),
+        test_sign6: ("$-8", "a"),
+        test_sign7: ("(-8)", "a"),
+        test_sign8: ("-apple", "an"),
         // Mixed case
         test_mc1: ("Alien", "An"), // Title Case
         test_mc2: ("anteLoPe", "an"), // mixed case
@@ -405,6 +883,51 @@ mod tests {
         test_other_adv10: ("usuriously", "a"),
     }
 
+    macro_rules! tests_options_with_dialect {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (dialect, input, expected) = $value;
+                let options = &(Options::with_dialect(dialect));
+
+                assert_eq!(expected, get_a_or_an_options(input, options));
+                assert_eq!(expected.to_lowercase() == "an", is_an_options(input, options) == Is::An);
+            }
+        )*
+        }
+    }
+
+    macro_rules! tests_options_with_pronounced_acronyms {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let options = &(Options::with_pronounced_acronyms());
+
+                let (input, expected) = $value;
+                assert_eq!(expected, get_a_or_an_options(input, options));
+                assert_eq!(expected.to_lowercase() == "an", is_an_options(input, options) == Is::An);
+            }
+        )*
+        }
+    }
+
+    macro_rules! tests_options_with_all_caps {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (all_caps, input, expected) = $value;
+                let options = &(Options::with_all_caps(all_caps));
+
+                assert_eq!(expected, get_a_or_an_options(input, options));
+                assert_eq!(expected.to_lowercase() == "an", is_an_options(input, options) == Is::An);
+            }
+        )*
+        }
+    }
+
     tests_options_with_colloquial! {
         test_colloquial_ac1: ("EU", "an"),
         test_colloquial_ac2: ("FIFA", "an"),
@@ -438,6 +961,11 @@ mod tests {
         test_colloquial_ny1100: ("1100", "an"), // 'eleven hundred'
         test_colloquial_ny1800: ("1800", "an"),
         test_colloquial_ny1892: ("1892", "an"),
+        // ordinals - years
+        test_colloquial_ord1: ("1100th", "an"), // 'eleven hundredth'
+        test_colloquial_ord2: ("1800th", "an"),
+        test_colloquial_ord3: ("18th", "an"),
+        test_colloquial_ord4: ("1st", "a"),
         // single letters - irregular
         test_single_irregular_f: ("f", "an"),
         test_single_irregular_h: ("h", "an"),
@@ -452,4 +980,187 @@ mod tests {
         test_single_regular_a: ("a", "an"),
         test_single_regular_b: ("b", "a"),
     }
+
+    tests_options_with_dialect! {
+        test_dialect_us1: (Dialect::Us, "herb", "an"),
+        test_dialect_us2: (Dialect::Us, "herbal", "an"),
+        test_dialect_us3: (Dialect::Us, "herbs", "an"),
+        test_dialect_us4: (Dialect::Us, "herbalist", "an"),
+        test_dialect_us5: (Dialect::Us, "Herb garden", "An"),
+        test_dialect_uk1: (Dialect::Uk, "herb", "a"),
+        test_dialect_uk2: (Dialect::Uk, "herbal", "a"),
+        test_dialect_uk3: (Dialect::Uk, "herbs", "a"),
+        test_dialect_uk4: (Dialect::Uk, "herbalist", "a"),
+        test_dialect_uk5: (Dialect::Uk, "Herb garden", "A"),
+        test_dialect_au1: (Dialect::Au, "herb", "a"),
+        test_dialect_au2: (Dialect::Au, "herbal tea", "a"),
+        test_dialect_au3: (Dialect::Au, "herbalist", "a"),
+        test_dialect_ie1: (Dialect::Ie, "herb", "a"),
+        test_dialect_ie2: (Dialect::Ie, "herbal tea", "a"),
+        test_dialect_ie3: (Dialect::Ie, "herbalist", "a"),
+        // hotel, historic and lieutenant
+        test_dialect_us6: (Dialect::Us, "hotel", "a"),
+        test_dialect_us7: (Dialect::Us, "historic", "a"),
+        test_dialect_us8: (Dialect::Us, "lieutenant", "a"),
+        test_dialect_uk6: (Dialect::Uk, "hotel", "an"),
+        test_dialect_uk7: (Dialect::Uk, "historic", "an"),
+        test_dialect_uk8: (Dialect::Uk, "historical novel", "an"),
+        test_dialect_uk9: (Dialect::Uk, "Hotels", "An"),
+        test_dialect_uk10: (Dialect::Uk, "lieutenant", "a"),
+        test_dialect_au4: (Dialect::Au, "hotel", "a"),
+        test_dialect_au5: (Dialect::Au, "historic", "a"),
+        test_dialect_au6: (Dialect::Au, "lieutenant", "a"),
+        test_dialect_ie4: (Dialect::Ie, "hotel", "an"),
+        test_dialect_ie5: (Dialect::Ie, "historic", "an"),
+        test_dialect_ie6: (Dialect::Ie, "lieutenant", "a"),
+        // the same in every dialect
+        test_dialect_uk_same1: (Dialect::Uk, "heir", "an"),
+        test_dialect_uk_same2: (Dialect::Uk, "honour", "an"),
+        test_dialect_uk_same3: (Dialect::Uk, "umbrella", "an"),
+        test_dialect_au_same1: (Dialect::Au, "hour", "an"),
+        test_dialect_ie_same1: (Dialect::Ie, "user", "a"),
+    }
+
+    tests_options_with_pronounced_acronyms! {
+        // built-in list
+        test_pronounced_ac1: ("NASA", "a"),
+        test_pronounced_ac2: ("NATO", "a"),
+        test_pronounced_ac3: ("FIFA", "a"),
+        test_pronounced_ac4: ("OPEC", "an"),
+        test_pronounced_ac5: ("UNICEF", "a"),
+        test_pronounced_ac6: ("NIMBY", "a"),
+        test_pronounced_ac7: ("AWOL", "an"),
+        // heuristic
+        test_pronounced_ac8: ("SMART", "a"),
+        test_pronounced_ac9: ("FOMO", "a"),
+        test_pronounced_ac10: ("EMEA", "an"),
+        // read out letter by letter
+        test_pronounced_ac11: ("FBI", "an"),
+        test_pronounced_ac12: ("HVAC", "an"),
+        test_pronounced_ac13: ("NYPD", "an"),
+        test_pronounced_ac14: ("MNM", "an"),
+        test_pronounced_ac15: ("USSR", "a"),
+        test_pronounced_ac16: ("HTML", "an"),
+        test_pronounced_ac17: ("UFO", "a"),
+        test_pronounced_ac18: ("IOU", "an"),
+        test_pronounced_ac19: ("MIA", "an"),
+        // words
+        test_pronounced_w1: ("umbrella", "an"),
+        test_pronounced_w2: ("user", "a"),
+    }
+
+    tests_options_with_all_caps! {
+        test_all_caps_words1: (AllCapsMode::Words, "UMBRELLA", "AN"),
+        test_all_caps_words2: (AllCapsMode::Words, "USER", "A"),
+        test_all_caps_words3: (AllCapsMode::Words, "HOUR", "AN"),
+        test_all_caps_words4: (AllCapsMode::Words, "APPLE PIE", "AN"),
+        test_all_caps_words5: (AllCapsMode::Words, "Umbrella", "An"),
+        test_all_caps_words6: (AllCapsMode::Words, "HTML", "an"), // no vowel: acronym
+        test_all_caps_words7: (AllCapsMode::Words, "F", "An"),
+        test_all_caps_auto1: (AllCapsMode::Auto, "HOUR", "AN"),
+        test_all_caps_auto2: (AllCapsMode::Auto, "HOURLY", "AN"),
+        test_all_caps_auto3: (AllCapsMode::Auto, "UNICORN", "A"),
+        test_all_caps_auto4: (AllCapsMode::Auto, "HISTORIC", "A"),
+        test_all_caps_auto5: (AllCapsMode::Auto, "UMBRELLA", "AN"),
+        test_all_caps_auto6: (AllCapsMode::Auto, "FBI", "an"),
+        test_all_caps_auto7: (AllCapsMode::Auto, "HTTPS", "an"),
+        test_all_caps_auto8: (AllCapsMode::Auto, "UFO", "A"), // a known word
+        test_all_caps_auto9: (AllCapsMode::Auto, "OWL", "an"), // too short to tell: acronym
+        test_all_caps_words8: (AllCapsMode::Words, "FBI", "an"), // looks like an initialism
+        test_all_caps_words9: (AllCapsMode::Words, "HAT", "A"),
+        test_all_caps_acronyms1: (AllCapsMode::Acronyms, "UMBRELLA", "a"),
+        test_all_caps_acronyms2: (AllCapsMode::Acronyms, "HOUR", "an"),
+    }
+
+    macro_rules! tests_options_with_number_locale {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (number_locale, input, expected) = $value;
+                let options = &(Options::with_number_locale(number_locale));
+
+                assert_eq!(expected, get_a_or_an_options(input, options));
+                assert_eq!(expected == "an", is_an_options(input, options) == Is::An);
+            }
+        )*
+        }
+    }
+
+    tests_options_with_number_locale! {
+        test_locale_de1: (NumberLocale::De, "18.000", "an"),
+        test_locale_de2: (NumberLocale::De, "1.800", "a"),
+        test_locale_de3: (NumberLocale::De, "8,5", "an"),
+        test_locale_de4: (NumberLocale::De, "11.000.000", "an"),
+        test_locale_fr1: (NumberLocale::Fr, "18\u{202F}000", "an"),
+        test_locale_fr2: (NumberLocale::Fr, "180\u{00A0}000", "a"),
+        test_locale_fr3: (NumberLocale::Fr, "1,8", "a"),
+        test_locale_ch1: (NumberLocale::Ch, "8'000", "an"),
+        test_locale_ch2: (NumberLocale::Ch, "18\u{2019}000", "an"),
+        test_locale_ch3: (NumberLocale::Ch, "1'800.5", "a"),
+        test_locale_us1: (NumberLocale::Us, "8'000", "an"), // not grouped in the US: the leading digits
+        test_locale_us2: (NumberLocale::Us, "18.000", "an"),
+    }
+
+    macro_rules! tests_options_with_roman_numerals {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (input, expected) = $value;
+                let options = &(Options::with_roman_numerals());
+
+                assert_eq!(expected, get_a_or_an_options(input, options));
+                assert_eq!(expected.to_lowercase() == "an", is_an_options(input, options) == Is::An);
+            }
+        )*
+        }
+    }
+
+    tests_options_with_roman_numerals! {
+        test_roman1: ("XI", "an"),
+        test_roman2: ("VIII", "an"),
+        test_roman3: ("LVIII", "a"),
+        test_roman4: ("MCM", "a"),
+        test_roman5: ("XVIII", "an"),
+        test_roman6: ("II", "a"),
+        test_roman7: ("LXXX", "an"), // eighty
+        test_roman8: ("XI.", "an"),
+        // not numerals: acronyms or words
+        test_roman9: ("I", "An"),
+        test_roman10: ("MIX", "an"),
+        test_roman11: ("CD", "a"),
+        test_roman12: ("XL", "an"),
+        test_roman13: ("FBI", "an"),
+        test_roman14: ("vi", "a"),
+    }
+
+    macro_rules! tests_options_with_extensions {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (extensions, input, expected) = $value;
+                let options = &(Options::with_extensions(extensions));
+
+                assert_eq!(expected, get_a_or_an_options(input, options));
+                assert_eq!(expected == "an", is_an_options(input, options) == Is::An);
+            }
+        )*
+        }
+    }
+
+    tests_options_with_extensions! {
+        test_ext1: (ExtensionStyle::Word, ".exe", "an"),
+        test_ext2: (ExtensionStyle::Letters, ".exe", "an"),
+        test_ext3: (ExtensionStyle::Word, ".mov", "a"),
+        test_ext4: (ExtensionStyle::Letters, ".mov", "an"),
+        test_ext5: (ExtensionStyle::Word, ".heic", "a"),
+        test_ext6: (ExtensionStyle::Letters, ".heic", "an"),
+        test_ext7: (ExtensionStyle::Word, ".png", "a"),
+        test_ext8: (ExtensionStyle::Letters, ".png", "a"),
+        test_ext9: (ExtensionStyle::Word, ".svg", "an"),
+        test_ext10: (ExtensionStyle::Letters, ".uml", "a"),
+        test_ext11: (ExtensionStyle::Letters, ".com", "a"),
+    }
 }
@@ -0,0 +1,1020 @@
+use std::borrow::Cow;
+
+use crate::core_is_an;
+use crate::options::{Dialect, NumberLocale, Options};
+use crate::string_helper;
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+// short scale, as used in US and modern UK English
+const SCALES: [&str; 12] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+];
+
+// An empty string, if the text is not a number.
+pub fn number_to_words(number: &str, options: &Options) -> String {
+    let number: &str = &normalize_digits(number);
+
+    year_to_words(number)
+        .or_else(|| radix_to_words(number))
+        .or_else(|| currency_to_words(number, options))
+        .or_else(|| scientific_to_words(number, options))
+        .or_else(|| range_to_words(number, options))
+        .or_else(|| time_to_words(number))
+        .or_else(|| fraction_to_words(number, options))
+        .or_else(|| with_suffix_to_words(number, options))
+        .unwrap_or_default()
+}
+
+// The zero of each set of decimal digits, other than ASCII: Arabic-Indic, Extended Arabic-Indic, NKo, Devanagari,
+// Bengali, Gurmukhi, Gujarati, Oriya, Tamil, Telugu, Kannada, Malayalam, Thai, Lao, Tibetan, Myanmar, Khmer, Mongolian and full-width.
+const DIGIT_ZEROS: [u32; 19] = [
+    0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6, 0x0B66, 0x0BE6, 0x0C66, 0x0CE6, 0x0D66,
+    0x0E50, 0x0ED0, 0x0F20, 0x1040, 0x17E0, 0x1810, 0xFF10,
+];
+
+// '８' or '٨': the digits of another script, as ASCII digits
+pub fn normalize_digits(number: &str) -> Cow<'_, str> {
+    if number.is_ascii() {
+        return Cow::Borrowed(number);
+    }
+
+    let to_ascii = |ch: char| {
+        DIGIT_ZEROS
+            .iter()
+            .find(|zero| (**zero..**zero + 10).contains(&(ch as u32)))
+            .map_or(ch, |zero| char::from(b'0' + (ch as u32 - zero) as u8))
+    };
+
+    Cow::Owned(number.chars().map(to_ascii).collect())
+}
+
+// The letters of hex numbers, and of the prefixes '0x', '0b' and '0o', are read out by name.
+fn letter_name(letter: char) -> Option<&'static str> {
+    let name = match letter.to_ascii_lowercase() {
+        'a' => "ay",
+        'b' => "bee",
+        'c' => "cee",
+        'd' => "dee",
+        'e' => "ee",
+        'f' => "eff",
+        'o' => "oh",
+        'x' => "ex",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+// '0x8F', '0b1010' or '0o17': 'oh ex eight eff', 'oh bee one zero one zero', 'oh oh one seven'
+fn radix_to_words(number: &str) -> Option<String> {
+    if !number.starts_with('0') || number.len() < 3 {
+        return None;
+    }
+
+    let prefix = number[1..].chars().next()?;
+    let radix = match prefix {
+        'x' | 'X' => 16,
+        'b' | 'B' => 2,
+        'o' | 'O' => 8,
+        _ => return None,
+    };
+
+    let digits = &number[2..];
+    if !digits.chars().all(|ch| ch.is_digit(radix)) {
+        return None;
+    }
+
+    let mut words = vec!["oh".to_string(), letter_name(prefix)?.to_string()];
+    for digit in digits.chars() {
+        match digit.to_digit(10) {
+            Some(value) => words.push(ONES[value as usize].to_string()),
+            None => words.push(letter_name(digit)?.to_string()),
+        }
+    }
+
+    Some(words.join(" "))
+}
+
+// (symbol, singular, plural), for each of `string_helper::is_currency_symbol()`
+const CURRENCIES: &[(char, &str, &str)] = &[
+    ('$', "dollar", "dollars"),
+    ('\u{00A2}', "cent", "cents"),
+    ('\u{00A3}', "pound", "pounds"),
+    ('\u{00A5}', "yen", "yen"),
+    ('\u{20A1}', "colon", "colones"),
+    ('\u{20A6}', "naira", "naira"),
+    ('\u{20A9}', "won", "won"),
+    ('\u{20AA}', "shekel", "shekels"),
+    ('\u{20AB}', "dong", "dong"),
+    ('\u{20AC}', "euro", "euros"),
+    ('\u{20AD}', "kip", "kip"),
+    ('\u{20B1}', "peso", "pesos"),
+    ('\u{20B2}', "guarani", "guaranis"),
+    ('\u{20B4}', "hryvnia", "hryvnias"),
+    ('\u{20B5}', "cedi", "cedis"),
+    ('\u{20B8}', "tenge", "tenge"),
+    ('\u{20B9}', "rupee", "rupees"),
+    ('\u{20BA}', "lira", "lira"),
+    ('\u{20BD}', "ruble", "rubles"),
+    ('\u{0E3F}', "baht", "baht"),
+];
+
+// '$8' or '€1': the currency is read out after the number: 'eight dollars', 'one euro'
+fn currency_to_words(number: &str, options: &Options) -> Option<String> {
+    let symbol = number.chars().next()?;
+    let (_, singular, plural) = CURRENCIES
+        .iter()
+        .find(|(currency, _, _)| *currency == symbol)?;
+
+    let amount = &number[symbol.len_utf8()..];
+    if !amount.starts_with(|ch: char| ch.is_ascii_digit()) {
+        return None;
+    }
+
+    let words = number_to_words(amount, options);
+    if words.is_empty() {
+        return None;
+    }
+
+    let currency = if words == "one" { singular } else { plural };
+
+    Some(format!("{} {}", words, currency))
+}
+
+// '8e10', '1.8e-5', '1.8×10³' or '10^3': 'eight e ten', 'one point eight e minus five',
+// 'one point eight times ten to the third', 'ten to the third'
+fn scientific_to_words(number: &str, options: &Options) -> Option<String> {
+    if let Some(times) = number.find('\u{00D7}') {
+        let mantissa = decimal_to_words(&number[..times], options)?;
+        let power = power_to_words(&number[times + '\u{00D7}'.len_utf8()..])?;
+
+        return Some(format!("{} times {}", mantissa, power));
+    }
+
+    if let Some(power) = power_to_words(number) {
+        return Some(power);
+    }
+
+    let e = number.find(|ch| ch == 'e' || ch == 'E')?;
+    let (mantissa, exponent) = (&number[..e], &number[e + 1..]);
+    let (sign, exponent) = match exponent.chars().next() {
+        Some('-') | Some('\u{2212}') => {
+            ("minus ", &exponent[exponent.chars().next()?.len_utf8()..])
+        }
+        Some('+') => ("", &exponent[1..]),
+        _ => ("", exponent),
+    };
+    if !is_digits(exponent) {
+        return None;
+    }
+
+    Some(format!(
+        "{} e {}{}",
+        decimal_to_words(mantissa, options)?,
+        sign,
+        integer_to_words(exponent)
+    ))
+}
+
+// '10³', '10⁻³' or '10^3': 'ten to the third', 'ten to the minus third'
+fn power_to_words(power: &str) -> Option<String> {
+    let end = power.find(|ch: char| !ch.is_ascii_digit())?;
+    let (base, exponent) = power.split_at(end);
+
+    let (is_negative, digits) = if exponent.starts_with('^') {
+        let exponent = &exponent[1..];
+        let digits = exponent.trim_start_matches('-');
+        (digits.len() < exponent.len(), digits.to_string())
+    } else {
+        let digits = exponent.trim_start_matches('\u{207B}');
+        let is_negative = digits.len() < exponent.len();
+        let digits = digits
+            .chars()
+            .map(superscript_digit)
+            .collect::<Option<String>>()?;
+        (is_negative, digits)
+    };
+
+    if !is_digits(base) || !is_digits(&digits) {
+        return None;
+    }
+
+    let sign = if is_negative { "minus " } else { "" };
+    Some(format!(
+        "{} to the {}{}",
+        integer_to_words(base),
+        sign,
+        ordinal_words(&integer_to_words(&digits))
+    ))
+}
+
+fn superscript_digit(ch: char) -> Option<char> {
+    match ch {
+        '\u{2070}' => Some('0'),
+        '\u{00B9}' => Some('1'),
+        '\u{00B2}' => Some('2'),
+        '\u{00B3}' => Some('3'),
+        '\u{2074}'..='\u{2079}' => Some(char::from(b'4' + (ch as u32 - 0x2074) as u8)),
+        _ => None,
+    }
+}
+
+// '8–10' or '11-18': 'eight to ten', 'eleven to eighteen'
+fn range_to_words(range: &str, options: &Options) -> Option<String> {
+    let (offset, dash) = range
+        .char_indices()
+        .skip(1)
+        .find(|(_, ch)| *ch == '-' || *ch == '\u{2013}')?;
+    let (from, to) = (&range[..offset], &range[offset + dash.len_utf8()..]);
+
+    if !to.starts_with(|ch: char| ch.is_ascii_digit())
+        || to.contains(|ch| ch == '-' || ch == '\u{2013}')
+    {
+        return None;
+    }
+
+    let from = decimal_to_words(from, options)?;
+    let to = with_suffix_to_words(to, options)?;
+
+    Some(format!("{} to {}", from, to))
+}
+
+// '8:30' or '11:05pm': 'eight thirty', 'eleven oh five p m'
+fn time_to_words(time: &str) -> Option<String> {
+    let colon = time.find(':')?;
+    let (hours, rest) = (&time[..colon], &time[colon + 1..]);
+    let minutes_end = rest
+        .find(|ch: char| !ch.is_ascii_digit())
+        .unwrap_or(rest.len());
+    let (minutes, suffix) = rest.split_at(minutes_end);
+
+    let is_clock_suffix =
+        suffix.is_empty() || suffix.eq_ignore_ascii_case("am") || suffix.eq_ignore_ascii_case("pm");
+    if !is_digits(hours)
+        || hours.len() > 2
+        || !is_digits(minutes)
+        || minutes.len() != 2
+        || !is_clock_suffix
+    {
+        return None;
+    }
+
+    let (hours, minutes) = (hours.parse::<usize>().ok()?, minutes.parse::<usize>().ok()?);
+    if hours > 24 || minutes > 59 {
+        return None;
+    }
+
+    let mut words = vec![tens_to_words(hours)];
+    match minutes {
+        0 if suffix.is_empty() => words.push("o'clock".to_string()),
+        0 => {}
+        1..=9 => words.push(format!("oh {}", ONES[minutes])),
+        _ => words.push(tens_to_words(minutes)),
+    }
+    if !suffix.is_empty() {
+        words.push(clock_suffix_to_words(suffix));
+    }
+
+    Some(words.join(" "))
+}
+
+// 'am' and 'pm' are read out letter by letter
+fn clock_suffix_to_words(suffix: &str) -> String {
+    let letters: Vec<String> = suffix
+        .to_lowercase()
+        .chars()
+        .map(|ch| ch.to_string())
+        .collect();
+
+    letters.join(" ")
+}
+
+const VULGAR_FRACTIONS: [(char, usize, usize); 18] = [
+    ('\u{00BC}', 1, 4),
+    ('\u{00BD}', 1, 2),
+    ('\u{00BE}', 3, 4),
+    ('\u{2150}', 1, 7),
+    ('\u{2151}', 1, 9),
+    ('\u{2152}', 1, 10),
+    ('\u{2153}', 1, 3),
+    ('\u{2154}', 2, 3),
+    ('\u{2155}', 1, 5),
+    ('\u{2156}', 2, 5),
+    ('\u{2157}', 3, 5),
+    ('\u{2158}', 4, 5),
+    ('\u{2159}', 1, 6),
+    ('\u{215A}', 5, 6),
+    ('\u{215B}', 1, 8),
+    ('\u{215C}', 3, 8),
+    ('\u{215D}', 5, 8),
+    ('\u{215E}', 7, 8),
+];
+
+// '1/8' or '⅛': 'one eighth', '3/8': 'three eighths', '8½': 'eight and a half'
+fn fraction_to_words(fraction: &str, options: &Options) -> Option<String> {
+    if let Some(vulgar) = fraction.chars().last() {
+        if let Some((_, numerator, denominator)) =
+            VULGAR_FRACTIONS.iter().find(|(ch, _, _)| *ch == vulgar)
+        {
+            let whole = &fraction[..fraction.len() - vulgar.len_utf8()];
+            if whole.is_empty() {
+                return Some(fraction_words(*numerator, *denominator));
+            }
+
+            let whole = decimal_to_words(whole, options)?;
+            let part = if *numerator == 1 {
+                let denominator = denominator_words(*denominator, false);
+                let article = if core_is_an::is_naively_an(&denominator) {
+                    "an"
+                } else {
+                    "a"
+                };
+                format!("{} {}", article, denominator)
+            } else {
+                fraction_words(*numerator, *denominator)
+            };
+
+            return Some(format!("{} and {}", whole, part));
+        }
+    }
+
+    let slash = fraction.find('/')?;
+    let (numerator, denominator) = (&fraction[..slash], &fraction[slash + 1..]);
+    if !is_digits(numerator) || !is_digits(denominator) || denominator.len() > 3 * SCALES.len() {
+        return None;
+    }
+
+    let numerator = numerator.parse::<usize>().ok()?;
+    let denominator = denominator.parse::<usize>().ok()?;
+    if denominator == 0 {
+        return None;
+    }
+
+    Some(fraction_words(numerator, denominator))
+}
+
+fn fraction_words(numerator: usize, denominator: usize) -> String {
+    format!(
+        "{} {}",
+        integer_to_words(&numerator.to_string()),
+        denominator_words(denominator, numerator != 1)
+    )
+}
+
+fn denominator_words(denominator: usize, is_plural: bool) -> String {
+    let words = match denominator {
+        1 => return "over one".to_string(),
+        2 => "half".to_string(),
+        4 => "quarter".to_string(),
+        _ => ordinal_words(&integer_to_words(&denominator.to_string())),
+    };
+
+    match (is_plural, words.as_str()) {
+        (true, "half") => "halves".to_string(),
+        (true, _) => format!("{}s", words),
+        (false, _) => words,
+    }
+}
+
+// 'eighteenth', 'twenty-first', 'one hundredth': only the last word changes
+fn ordinal_words(words: &str) -> String {
+    let start = words
+        .rfind(|ch| ch == ' ' || ch == '-')
+        .map_or(0, |offset| offset + 1);
+    let (head, last) = words.split_at(start);
+
+    let last = match last {
+        "one" => "first".to_string(),
+        "two" => "second".to_string(),
+        "three" => "third".to_string(),
+        "five" => "fifth".to_string(),
+        "eight" => "eighth".to_string(),
+        "nine" => "ninth".to_string(),
+        "twelve" => "twelfth".to_string(),
+        _ if last.ends_with('y') => format!("{}ieth", &last[..last.len() - 1]),
+        _ => format!("{}th", last),
+    };
+
+    format!("{}{}", head, last)
+}
+
+// (symbol, singular, plural)
+const UNITS: &[(&str, &str, &str)] = &[
+    ("%", "percent", "percent"),
+    ("am", "a m", "a m"),
+    ("pm", "p m", "p m"),
+    ("mg", "milligram", "milligrams"),
+    ("g", "gram", "grams"),
+    ("kg", "kilogram", "kilograms"),
+    ("lb", "pound", "pounds"),
+    ("lbs", "pound", "pounds"),
+    ("oz", "ounce", "ounces"),
+    ("mm", "millimeter", "millimeters"),
+    ("cm", "centimeter", "centimeters"),
+    ("m", "meter", "meters"),
+    ("km", "kilometer", "kilometers"),
+    ("ft", "foot", "feet"),
+    ("mi", "mile", "miles"),
+    ("mph", "mile per hour", "miles per hour"),
+    ("ml", "milliliter", "milliliters"),
+    ("l", "liter", "liters"),
+    ("L", "liter", "liters"),
+    ("ms", "millisecond", "milliseconds"),
+    ("min", "minute", "minutes"),
+    ("h", "hour", "hours"),
+    ("hr", "hour", "hours"),
+    ("Hz", "hertz", "hertz"),
+    ("kHz", "kilohertz", "kilohertz"),
+    ("MHz", "megahertz", "megahertz"),
+    ("GHz", "gigahertz", "gigahertz"),
+    ("KB", "kilobyte", "kilobytes"),
+    ("kB", "kilobyte", "kilobytes"),
+    ("MB", "megabyte", "megabytes"),
+    ("GB", "gigabyte", "gigabytes"),
+    ("TB", "terabyte", "terabytes"),
+    ("PB", "petabyte", "petabytes"),
+];
+
+// '18th': 'eighteenth', '8kg': 'eight kilograms', '1TB': 'one terabyte', '8 %': 'eight percent'
+fn with_suffix_to_words(number: &str, options: &Options) -> Option<String> {
+    let end = number_end(number, options.number_locale);
+    let (value, suffix) = number.split_at(end);
+    let suffix = suffix.trim_start();
+
+    let words = decimal_to_words(value, options)?;
+    if suffix.is_empty() {
+        return Some(words);
+    }
+
+    let is_integer = value
+        .chars()
+        .all(|ch| ch.is_ascii_digit() || is_group_mark(ch, options.number_locale));
+    if is_integer && ORDINAL_SUFFIXES.contains(&suffix.to_lowercase().as_str()) {
+        return Some(ordinal_words(&words));
+    }
+
+    let (_, singular, plural) = UNITS.iter().find(|(symbol, _, _)| *symbol == suffix)?;
+    let unit = if words == "one" { singular } else { plural };
+    let unit = if options.dialect == Dialect::Us {
+        unit.to_string()
+    } else {
+        unit.replace("meter", "metre").replace("liter", "litre")
+    };
+
+    Some(format!("{} {}", words, unit))
+}
+
+pub const ORDINAL_SUFFIXES: &[&str] = &["st", "nd", "rd", "th"];
+
+// The end of the number at the start of the text: a sign, then digits with any group marks or a decimal mark between them.
+fn number_end(text: &str, locale: NumberLocale) -> usize {
+    let mut previous = None;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((offset, ch)) = chars.next() {
+        let is_sign = offset == 0 && (ch == '-' || ch == '+');
+        let is_mark = (is_group_mark(ch, locale) || ch == decimal_mark(locale))
+            && chars
+                .peek()
+                .map_or(false, |(_, next)| next.is_ascii_digit())
+            && previous.map_or(true, |previous: char| previous.is_ascii_digit());
+        if !ch.is_ascii_digit() && !is_sign && !is_mark {
+            return offset;
+        }
+
+        previous = Some(ch);
+    }
+
+    text.len()
+}
+
+// '18,000.5', '-8' or '.5': 'eighteen thousand point five', 'minus eight', 'point five'
+fn decimal_to_words(number: &str, options: &Options) -> Option<String> {
+    let mut words: Vec<String> = Vec::new();
+
+    let mut rest = number;
+    if rest.starts_with('-') || rest.starts_with('+') {
+        words.push(
+            if rest.starts_with('-') {
+                "minus"
+            } else {
+                "plus"
+            }
+            .to_string(),
+        );
+        rest = &rest[1..];
+    }
+
+    let locale = options.number_locale;
+    let point = decimal_mark(locale);
+    let (integer, fraction) = match rest.find(point) {
+        Some(offset) => (&rest[..offset], Some(&rest[offset + point.len_utf8()..])),
+        None => (rest, None),
+    };
+
+    if !is_integer_part(integer, locale) || !fraction.map_or(true, is_fraction_part) {
+        return None;
+    }
+
+    let digits: String = integer.chars().filter(|ch| ch.is_ascii_digit()).collect();
+    if digits.is_empty() && fraction.is_none() {
+        return None;
+    }
+
+    if !digits.is_empty() {
+        let is_year =
+            options.are_numbers_colloquial && fraction.is_none() && integer.len() == digits.len();
+        match colloquial_year(&digits).filter(|_| is_year) {
+            Some(year) => words.push(year),
+            None => words.push(integer_to_words(&digits)),
+        }
+    }
+
+    if let Some(fraction) = fraction {
+        words.push("point".to_string());
+        words.push(digit_by_digit(fraction));
+    }
+
+    Some(words.join(" "))
+}
+
+// A decade or a year with an apostrophe: ''80s' as 'eighties', '1800s' as 'eighteen hundreds', ''18' as 'eighteen'.
+// A decade is always read out as a year, even if numbers are not colloquial.
+pub fn year_to_words(year: &str) -> Option<String> {
+    let (is_abbreviated, rest) = match year.chars().next() {
+        Some(first) if string_helper::is_apostrophe(first) => (true, &year[first.len_utf8()..]),
+        _ => (false, year),
+    };
+
+    let end = rest
+        .find(|ch: char| !ch.is_ascii_digit())
+        .unwrap_or(rest.len());
+    let (digits, suffix) = rest.split_at(end);
+    let is_plural = !suffix.is_empty();
+    if !is_digits(digits) || (is_plural && suffix != "s" && !is_apostrophe_s(suffix)) {
+        return None;
+    }
+
+    let words = if is_abbreviated {
+        if digits.len() != 2 {
+            return None;
+        }
+
+        match digits.parse::<usize>().ok()? {
+            0 => "oh oh".to_string(),
+            year @ 1..=9 => format!("oh {}", ONES[year]),
+            year => tens_to_words(year),
+        }
+    } else {
+        if !is_plural || digits.len() > 4 {
+            return None;
+        }
+
+        let words = colloquial_year(digits).unwrap_or_else(|| integer_to_words(digits));
+
+        // '100s' and '1000s' are 'hundreds' and 'thousands'
+        let is_power_of_ten = digits.starts_with('1') && digits[1..].chars().all(|ch| ch == '0');
+        if is_power_of_ten && digits.len() > 2 {
+            words.trim_start_matches("one ").to_string()
+        } else {
+            words
+        }
+    };
+
+    if is_plural {
+        return Some(plural(&words));
+    }
+
+    Some(words)
+}
+
+fn is_apostrophe_s(suffix: &str) -> bool {
+    let mut chars = suffix.chars();
+    chars.next().map_or(false, string_helper::is_apostrophe) && chars.as_str() == "s"
+}
+
+// 'eighties', 'sixes', 'hundreds'
+fn plural(words: &str) -> String {
+    if words.ends_with('y') {
+        return format!("{}ies", &words[..words.len() - 1]);
+    }
+
+    if words.ends_with('x') {
+        return format!("{}es", words);
+    }
+
+    format!("{}s", words)
+}
+
+fn decimal_mark(locale: NumberLocale) -> char {
+    match locale {
+        NumberLocale::Us | NumberLocale::Ch => '.',
+        NumberLocale::De | NumberLocale::Fr => ',',
+    }
+}
+
+fn is_group_mark(ch: char, locale: NumberLocale) -> bool {
+    match locale {
+        NumberLocale::Us => ch == ',',
+        NumberLocale::De => ch == '.',
+        NumberLocale::Fr => " \u{00A0}\u{202F}\u{2009}".contains(ch),
+        NumberLocale::Ch => "'\u{2019}".contains(ch),
+    }
+}
+
+// '18,000' or '1800': digits, with group marks only between groups of 3 digits
+fn is_integer_part(integer: &str, locale: NumberLocale) -> bool {
+    if integer.is_empty() {
+        return true;
+    }
+
+    let groups: Vec<&str> = integer.split(|ch| is_group_mark(ch, locale)).collect();
+
+    groups.iter().all(|group| is_digits(group))
+        && groups.iter().skip(1).all(|group| group.len() == 3)
+        && (groups.len() == 1 || groups[0].len() <= 3)
+}
+
+fn is_fraction_part(fraction: &str) -> bool {
+    is_digits(fraction)
+}
+
+fn is_digits(text: &str) -> bool {
+    !text.is_empty() && text.chars().all(|ch| ch.is_ascii_digit())
+}
+
+fn digit_by_digit(digits: &str) -> String {
+    digits
+        .bytes()
+        .map(|digit| ONES[(digit - b'0') as usize])
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+// '007': a code is read out digit by digit, and its leading zeros are 'oh', like the '0' of '0x8F'
+fn code_to_words(digits: &str) -> String {
+    let zeros = digits.len() - digits.trim_start_matches('0').len();
+
+    let mut words = vec!["oh"; zeros];
+    words.extend(
+        digits[zeros..]
+            .bytes()
+            .map(|digit| ONES[(digit - b'0') as usize]),
+    );
+
+    words.join(" ")
+}
+
+fn integer_to_words(digits: &str) -> String {
+    if digits.len() > 1 && digits.starts_with('0') {
+        return code_to_words(digits);
+    }
+
+    // a number too large to have a name: read out each digit
+    if digits.len() > 3 * SCALES.len() {
+        return digit_by_digit(digits);
+    }
+
+    let mut groups = Vec::new();
+    let mut end = digits.len();
+    while end > 0 {
+        let start = end.saturating_sub(3);
+        groups.push(digits[start..end].parse::<usize>().unwrap_or(0));
+        end = start;
+    }
+
+    let mut words = Vec::new();
+    for (scale, group) in groups.iter().enumerate().rev() {
+        if *group == 0 {
+            continue;
+        }
+
+        words.push(hundreds_to_words(*group));
+        if scale > 0 {
+            words.push(SCALES[scale].to_string());
+        }
+    }
+
+    if words.is_empty() {
+        return ONES[0].to_string();
+    }
+
+    words.join(" ")
+}
+
+fn hundreds_to_words(number: usize) -> String {
+    let hundreds = number / 100;
+    let rest = number % 100;
+
+    let mut words = Vec::new();
+    if hundreds > 0 {
+        words.push(format!("{} hundred", ONES[hundreds]));
+    }
+    if rest > 0 || hundreds == 0 {
+        words.push(tens_to_words(rest));
+    }
+
+    words.join(" ")
+}
+
+fn tens_to_words(number: usize) -> String {
+    if number < 20 {
+        return ONES[number].to_string();
+    }
+
+    match number % 10 {
+        0 => TENS[number / 10].to_string(),
+        ones => format!("{}-{}", TENS[number / 10], ONES[ones]),
+    }
+}
+
+// A 4 digit year, read in pairs: 'eighteen hundred', 'nineteen oh five', 'eighteen ninety-two'.
+// Round thousands and the 2000s up to 2009 are read as normal: 'two thousand', 'two thousand five'.
+fn colloquial_year(digits: &str) -> Option<String> {
+    if digits.len() != 4 || digits.starts_with('0') {
+        return None;
+    }
+
+    let year = digits.parse::<usize>().ok()?;
+    let (century, rest) = (year / 100, year % 100);
+
+    if (century % 10 == 0 && rest == 0) || (century == 20 && rest < 10) {
+        return None;
+    }
+
+    let rest = match rest {
+        0 => "hundred".to_string(),
+        1..=9 => format!("oh {}", ONES[rest]),
+        _ => tens_to_words(rest),
+    };
+
+    Some(format!("{} {}", tens_to_words(century), rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_words(number: &str) -> String {
+        number_to_words(number, &Options::default())
+    }
+
+    #[test]
+    fn number_to_words_integers() {
+        assert_eq!("zero", to_words("0"));
+        assert_eq!("eight", to_words("8"));
+        assert_eq!("eleven", to_words("11"));
+        assert_eq!("forty-two", to_words("42"));
+        assert_eq!("one hundred", to_words("100"));
+        assert_eq!("eight hundred eighteen", to_words("818"));
+        assert_eq!("one thousand eight hundred", to_words("1800"));
+        assert_eq!("eleven thousand", to_words("11000"));
+        assert_eq!("eighty million", to_words("80000000"));
+        assert_eq!("one million two", to_words("1000002"));
+        assert_eq!("eighteen quadrillion", to_words("18000000000000000"));
+    }
+
+    #[test]
+    fn number_to_words_separators_and_decimals() {
+        assert_eq!("eighty million", to_words("80,000,000"));
+        assert_eq!("one thousand", to_words("1,000"));
+        assert_eq!("one point eight", to_words("1.8"));
+        assert_eq!("zero point five", to_words("0.5"));
+        assert_eq!("point eight", to_words(".8"));
+        assert_eq!("eighteen point zero five", to_words("18.05"));
+        assert_eq!("minus eight", to_words("-8"));
+        assert_eq!("oh oh seven", to_words("007"));
+    }
+
+    #[test]
+    fn number_to_words_currencies() {
+        assert_eq!("eight dollars", to_words("$8"));
+        assert_eq!("one dollar", to_words("$1"));
+        assert_eq!("eighteen euros", to_words("\u{20AC}18"));
+        assert_eq!("one point five pounds", to_words("\u{00A3}1.5"));
+        assert_eq!("eight thousand yen", to_words("\u{00A5}8,000"));
+        // not a number
+        assert_eq!("", to_words("$"));
+        assert_eq!("", to_words("$x"));
+        assert_eq!("", to_words("$$8"));
+    }
+
+    #[test]
+    fn number_to_words_colloquial_years() {
+        let options = Options::with_colloquial();
+
+        assert_eq!("eighteen hundred", number_to_words("1800", &options));
+        assert_eq!("eleven hundred", number_to_words("1100", &options));
+        assert_eq!("eighteen ninety-two", number_to_words("1892", &options));
+        assert_eq!("nineteen oh five", number_to_words("1905", &options));
+        assert_eq!("twenty nineteen", number_to_words("2019", &options));
+        assert_eq!("two thousand five", number_to_words("2005", &options));
+        assert_eq!("one thousand", number_to_words("1000", &options));
+        // not a year
+        assert_eq!(
+            "one thousand eight hundred",
+            number_to_words("1,800", &options)
+        );
+        assert_eq!("eighteen thousand", number_to_words("18000", &options));
+    }
+
+    #[test]
+    fn number_to_words_locales() {
+        let words = |number, locale| number_to_words(number, &Options::with_number_locale(locale));
+
+        assert_eq!("eighteen thousand", words("18.000", NumberLocale::De));
+        assert_eq!("eighteen point five", words("18,5", NumberLocale::De));
+        assert_eq!(
+            "one million point two five",
+            words("1.000.000,25", NumberLocale::De)
+        );
+        assert_eq!("eighteen thousand", words("18 000", NumberLocale::Fr));
+        assert_eq!(
+            "eighteen thousand",
+            words("18\u{202F}000", NumberLocale::Fr)
+        );
+        assert_eq!(
+            "eighteen thousand",
+            words("18\u{00A0}000", NumberLocale::Fr)
+        );
+        assert_eq!("eight point one", words("8,1", NumberLocale::Fr));
+        assert_eq!("eight thousand", words("8'000", NumberLocale::Ch));
+        assert_eq!(
+            "eight thousand point five",
+            words("8\u{2019}000.5", NumberLocale::Ch)
+        );
+        // the marks of another locale
+        assert_eq!("", words("18,000.5", NumberLocale::De));
+        assert_eq!("", words("8'000", NumberLocale::Us));
+        // a year is not grouped
+        assert_eq!(
+            "eighteen hundred",
+            number_to_words(
+                "1800",
+                &Options {
+                    number_locale: NumberLocale::De,
+                    ..Options::with_colloquial()
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn number_to_words_years_and_decades() {
+        assert_eq!("eighties", to_words("'80s"));
+        assert_eq!("eighties", to_words("\u{2019}80s"));
+        assert_eq!("eighteen", to_words("'18"));
+        assert_eq!("oh five", to_words("'05"));
+        assert_eq!("nineteen eighties", to_words("1980s"));
+        assert_eq!("nineteen eighties", to_words("1980's"));
+        assert_eq!("eighteen hundreds", to_words("1800s"));
+        assert_eq!("eleven hundreds", to_words("1100s"));
+        assert_eq!("two thousands", to_words("2000s"));
+        assert_eq!("twenty tens", to_words("2010s"));
+        assert_eq!("eighties", to_words("80s"));
+        assert_eq!("sixes", to_words("6s"));
+        assert_eq!("tens", to_words("10s"));
+        assert_eq!("hundreds", to_words("100s"));
+        assert_eq!("thousands", to_words("1000s"));
+        // not a year
+        assert_eq!("", to_words("'8"));
+        assert_eq!("", to_words("'1980"));
+        assert_eq!("", to_words("18000s"));
+        assert_eq!("", to_words("80ss"));
+    }
+
+    #[test]
+    fn number_to_words_ordinals_and_units() {
+        assert_eq!("first", to_words("1st"));
+        assert_eq!("eighteenth", to_words("18th"));
+        assert_eq!("twenty-second", to_words("22nd"));
+        assert_eq!("one hundredth", to_words("100th"));
+        assert_eq!("eight kilograms", to_words("8kg"));
+        assert_eq!("eleven kilometers", to_words("11km"));
+        assert_eq!("eighteen gigabytes", to_words("18GB"));
+        assert_eq!("one terabyte", to_words("1TB"));
+        assert_eq!("one point five gigabytes", to_words("1.5GB"));
+        assert_eq!("eight percent", to_words("8%"));
+        assert_eq!("eleven p m", to_words("11pm"));
+        assert_eq!(
+            "eleven kilometres",
+            number_to_words("11km", &Options::with_dialect(Dialect::Uk))
+        );
+    }
+
+    #[test]
+    fn number_to_words_fractions_ranges_and_times() {
+        assert_eq!("one eighth", to_words("1/8"));
+        assert_eq!("three eighths", to_words("3/8"));
+        assert_eq!("one half", to_words("1/2"));
+        assert_eq!("three quarters", to_words("3/4"));
+        assert_eq!("eleven twentieths", to_words("11/20"));
+        assert_eq!("one eighth", to_words("\u{215B}"));
+        assert_eq!("eight and a half", to_words("8\u{00BD}"));
+        assert_eq!("one and an eighth", to_words("1\u{215B}"));
+        assert_eq!("eight to ten", to_words("8\u{2013}10"));
+        assert_eq!("eleven to eighteen", to_words("11-18"));
+        assert_eq!("eight to ten kilograms", to_words("8-10kg"));
+        assert_eq!("eight thirty", to_words("8:30"));
+        assert_eq!("eleven oh five", to_words("11:05"));
+        assert_eq!("eight o'clock", to_words("8:00"));
+        assert_eq!("eleven thirty p m", to_words("11:30pm"));
+        assert_eq!("eight a m", to_words("8:00am"));
+        // not a time
+        assert_eq!("", to_words("8:1\u{20AC}"));
+        assert_eq!("", to_words(":\u{2013}"));
+        assert_eq!("", to_words("8:305"));
+    }
+
+    #[test]
+    fn number_to_words_hex_and_scientific() {
+        assert_eq!("oh ex eight eff", to_words("0x8F"));
+        assert_eq!("oh ex dee ee ay dee", to_words("0xdead"));
+        assert_eq!("oh bee one zero one zero", to_words("0b1010"));
+        assert_eq!("oh oh one seven", to_words("0o17"));
+        // a leading zero is 'oh', in hex or in a code, but a zero on its own is 'zero'
+        assert_eq!("oh one two three", to_words("0123"));
+        assert_eq!("oh eight zero zero", to_words("0800"));
+        assert_eq!("zero", to_words("0"));
+        assert_eq!("zero point five", to_words("0.5"));
+        assert_eq!("eight e ten", to_words("8e10"));
+        assert_eq!("one point eight e minus five", to_words("1.8e-5"));
+        assert_eq!(
+            "one point eight times ten to the third",
+            to_words("1.8\u{00D7}10\u{00B3}")
+        );
+        assert_eq!(
+            "six times ten to the minus eighth",
+            to_words("6\u{00D7}10\u{207B}\u{2078}")
+        );
+        assert_eq!("ten to the third", to_words("10^3"));
+        assert_eq!("two to the eighth", to_words("2\u{2078}"));
+        // not in the notation
+        assert_eq!("", to_words("0b102"));
+        assert_eq!("", to_words("0o8"));
+        assert_eq!("", to_words("0xG"));
+        assert_eq!("", to_words("8e"));
+        assert_eq!("", to_words("10^"));
+    }
+
+    #[test]
+    fn number_to_words_non_ascii_digits() {
+        assert_eq!("eight", to_words("\u{FF18}"));
+        assert_eq!("eighteen", to_words("\u{FF11}\u{FF18}"));
+        assert_eq!("eight hundred", to_words("\u{0668}\u{0660}\u{0660}"));
+        assert_eq!("eleven", to_words("\u{06F1}\u{06F1}"));
+        assert_eq!("eight", to_words("\u{096E}"));
+    }
+
+    #[test]
+    fn number_to_words_not_a_number() {
+        assert_eq!("", to_words(""));
+        assert_eq!("", to_words("-"));
+        assert_eq!("", to_words("apple"));
+        assert_eq!("", to_words("8K"));
+        assert_eq!("", to_words("8:3"));
+        assert_eq!("", to_words("25:00"));
+        assert_eq!("", to_words("1/0"));
+        assert_eq!("", to_words("8-"));
+        assert_eq!("", to_words("8kgs"));
+        assert_eq!("", to_words("1,8"));
+        assert_eq!("", to_words("1.8.1"));
+    }
+}
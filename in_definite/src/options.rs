@@ -1,20 +1,172 @@
+#[derive(Debug, Clone, Default)]
 pub struct Options {
     /// If true, then a 4 digit number like '1800' is treated like 'eighteen hundred', so will use 'an'.
     ///
     /// Normally, such a number is treated like 'one thousand eight hundred', so would use 'a'.
     pub are_numbers_colloquial: bool,
+
+    /// The dialect of English, for words that are pronounced differently. For example: 'an herb' (US), 'a herb' (UK),
+    /// and 'a hotel' (US), 'an hotel' (UK). 'A lieutenant' is the same in every dialect.
+    pub dialect: Dialect,
+
+    /// How acronyms are read out. For example: 'an N-A-S-A' (letters) or 'a NASA' (pronounced as a word).
+    pub acronyms: AcronymMode,
+
+    /// How a word in ALL CAPS is read: as an acronym ('a UMBRELLA') or as an ordinary word ('AN UMBRELLA').
+    pub all_caps: AllCapsMode,
+
+    /// How numbers are written: the marks used to group thousands and for the decimal point. For example: '18,000.5' (US) or '18.000,5' (Germany).
+    pub number_locale: NumberLocale,
+
+    /// If true, then a Roman numeral in UPPER case like 'XI' or 'VIII' is read out as a number: 'an XI' ('an eleven'), 'an VIII' ('an eight').
+    ///
+    /// A single letter like 'I' and a numeral that is more often a word or an acronym, like 'MIX' or 'CD', are not read as numerals.
+    /// Normally, a Roman numeral is treated as an acronym: 'a VIII' ('a vee-eye-eye-eye').
+    pub roman_numerals: bool,
+
+    /// How a file extension like '.exe' is read out: as a word ('an exe') or letter by letter ('an E-X-E').
+    /// For example: 'a .mov' as a word, but 'an .mov' ('an M-O-V') as letters.
+    pub extensions: ExtensionStyle,
 }
 
 impl Options {
-    pub fn default() -> Options {
+    pub fn with_colloquial() -> Options {
         Options {
-            are_numbers_colloquial: false,
+            are_numbers_colloquial: true,
+            ..Options::default()
         }
     }
 
-    pub fn with_colloquial() -> Options {
+    pub fn with_dialect(dialect: Dialect) -> Options {
         Options {
-            are_numbers_colloquial: true,
+            dialect,
+            ..Options::default()
+        }
+    }
+
+    pub fn with_all_caps(all_caps: AllCapsMode) -> Options {
+        Options {
+            all_caps,
+            ..Options::default()
         }
     }
+
+    pub fn with_number_locale(number_locale: NumberLocale) -> Options {
+        Options {
+            number_locale,
+            ..Options::default()
+        }
+    }
+
+    pub fn with_roman_numerals() -> Options {
+        Options {
+            roman_numerals: true,
+            ..Options::default()
+        }
+    }
+
+    pub fn with_extensions(extensions: ExtensionStyle) -> Options {
+        Options {
+            extensions,
+            ..Options::default()
+        }
+    }
+
+    pub fn with_pronounced_acronyms() -> Options {
+        Options {
+            acronyms: AcronymMode::Pronounced,
+            ..Options::default()
+        }
+    }
+}
+
+/// A dialect of English.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Dialect {
+    /// American English (the default).
+    Us,
+    /// British English.
+    Uk,
+    /// Australian English, which follows British English, but says 'a hotel' and 'a historic'.
+    Au,
+    /// Irish English, which follows British English.
+    Ie,
+}
+
+impl Default for Dialect {
+    fn default() -> Dialect {
+        Dialect::Us
+    }
+}
+
+/// How acronyms are read out.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AcronymMode {
+    /// Every acronym is read out letter by letter: 'an N-A-S-A' (the default).
+    Letters,
+    /// Acronyms that can be pronounced as a word are read as a word: 'a NASA', 'an OPEC'. Other acronyms are read out letter by letter: 'an FBI'.
+    Pronounced,
+}
+
+impl Default for AcronymMode {
+    fn default() -> AcronymMode {
+        AcronymMode::Letters
+    }
+}
+
+/// How a word in ALL CAPS is read.
+///
+/// When an ALL CAPS word is read as an ordinary word, then the article is also in UPPER case: 'AN UMBRELLA'.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AllCapsMode {
+    /// Every ALL CAPS word is an acronym (the default).
+    Acronyms,
+    /// Every ALL CAPS word is an ordinary word, for text that is known to be in ALL CAPS, like a banner.
+    /// A word that looks like an initialism is still an acronym: no vowel ('HTML'), or an unusual start ('FBI').
+    Words,
+    /// An ALL CAPS word is an ordinary word if it is a known word, like 'HOUR', or if it has 4 or more letters and does not look like an initialism, like 'UMBRELLA'.
+    ///
+    /// When checking text, a sentence of at least 2 words that is all in ALL CAPS is read as for `AllCapsMode::Words`: 'AN UMBRELLA', 'AN FBI AGENT'.
+    Auto,
+}
+
+impl Default for AllCapsMode {
+    fn default() -> AllCapsMode {
+        AllCapsMode::Acronyms
+    }
+}
+
+/// How numbers are written: the marks used to group thousands and for the decimal point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumberLocale {
+    /// '18,000.5', as in the US and the UK (the default).
+    Us,
+    /// '18.000,5', as in Germany.
+    De,
+    /// '18 000,5', grouped with a space, a no-break space or a narrow no-break space, as in France.
+    Fr,
+    /// "18'000.5", as in Switzerland.
+    Ch,
+}
+
+impl Default for NumberLocale {
+    fn default() -> NumberLocale {
+        NumberLocale::Us
+    }
+}
+
+/// How a file extension is read out.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExtensionStyle {
+    /// An extension is read as a word, if it can be: 'an .exe', 'a .gif', 'a .mov' (the default).
+    /// An extension with no vowel is read out letter by letter: 'an .html', 'a .png'.
+    Word,
+    /// Every extension is read out letter by letter: 'an .exe' ('E-X-E'), 'an .mov' ('M-O-V').
+    Letters,
+}
+
+impl Default for ExtensionStyle {
+    fn default() -> ExtensionStyle {
+        ExtensionStyle::Word
+    }
 }
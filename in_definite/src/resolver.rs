@@ -0,0 +1,192 @@
+use std::borrow::Cow;
+
+use crate::core_is_an;
+use crate::core_is_an::Is;
+use crate::decision::{Accepted, Decision};
+use crate::dictionary::{Dictionary, Entry};
+use crate::error::ArticleError;
+use crate::options::Options;
+use crate::rules::pipeline::Pipeline;
+use crate::symbols::Symbols;
+use crate::text;
+use crate::text::Finding;
+
+/// Chooses 'a' or 'an', using the given options and a dictionary of entries that override the rules.
+///
+/// The rules are held in a `Pipeline`, which can be changed to insert, remove or reorder rules.
+///
+/// A resolver can be built once, and then reused for many words.
+///
+/// # Examples
+///
+/// ```
+/// use in_definite::{Entry, Is, Options, Resolver};
+///
+/// let mut resolver = Resolver::new(Options::default());
+/// resolver.add_entry(Entry::word("uber", Is::A));
+/// resolver.add_entry(Entry::case_sensitive("HMRC", Is::An));
+///
+/// assert_eq!("A", resolver.get_a_or_an("Uber driver"));
+/// assert_eq!("an", resolver.get_a_or_an("HMRC form"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Resolver {
+    options: Options,
+    dictionary: Dictionary,
+    pipeline: Pipeline,
+    symbols: Symbols,
+}
+
+impl Resolver {
+    pub fn new(options: Options) -> Resolver {
+        Resolver::with_dictionary(options, Dictionary::new())
+    }
+
+    pub fn with_dictionary(options: Options, dictionary: Dictionary) -> Resolver {
+        Resolver {
+            options,
+            dictionary,
+            pipeline: Pipeline::default(),
+            symbols: Symbols::new(),
+        }
+    }
+
+    /// Replace the table of symbols that are read out by name. See `Symbols`.
+    pub fn with_symbols(self, symbols: Symbols) -> Resolver {
+        Resolver { symbols, ..self }
+    }
+
+    pub fn add_entry(&mut self, entry: Entry) {
+        self.dictionary.add(entry);
+    }
+
+    /// Add a symbol that is read out by name, or replace how a symbol is read out.
+    pub fn add_symbol(&mut self, symbol: char, spoken: &str) {
+        self.symbols.add(symbol, spoken);
+    }
+
+    pub fn options(&self) -> &Options {
+        &self.options
+    }
+
+    pub fn dictionary(&self) -> &Dictionary {
+        &self.dictionary
+    }
+
+    pub fn symbols(&self) -> &Symbols {
+        &self.symbols
+    }
+
+    pub fn pipeline(&self) -> &Pipeline {
+        &self.pipeline
+    }
+
+    pub fn pipeline_mut(&mut self) -> &mut Pipeline {
+        &mut self.pipeline
+    }
+
+    /// Get 'a' or 'an' to match the given word. See `in_definite::get_a_or_an_options()`.
+    pub fn get_a_or_an(&self, word: &str) -> &'static str {
+        core_is_an::get_a_or_an(word, self, &self.options)
+    }
+
+    /// Returns whether the given word should be used with 'an' or 'a'. See `in_definite::is_an_options()`.
+    pub fn is_an(&self, word: &str) -> Is {
+        self.try_is_an(word).unwrap_or(Is::None)
+    }
+
+    /// See `in_definite::try_is_an()`.
+    pub fn try_is_an(&self, word: &str) -> Result<Is, ArticleError> {
+        core_is_an::try_decide(word, self, &self.options).map(|(is, _)| is)
+    }
+
+    /// See `in_definite::explain()`.
+    pub fn explain(&self, word: &str) -> Decision {
+        core_is_an::explain(word, self, &self.options)
+    }
+
+    /// See `in_definite::accepted()`.
+    pub fn accepted(&self, word: &str) -> Accepted {
+        core_is_an::accepted(word, self, &self.options)
+    }
+
+    /// See `in_definite::check_text()`.
+    pub fn check_text(&self, text: &str) -> Vec<Finding> {
+        text::check_text(text, self, &self.options)
+    }
+
+    /// See `in_definite::fix_text()`.
+    pub fn fix_text<'t>(&self, text: &'t str) -> Cow<'t, str> {
+        text::fix_text(text, self, &self.options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::decision::Reason;
+
+    #[test]
+    fn entries_override_rules() {
+        let mut resolver = Resolver::new(Options::default());
+        resolver.add_entry(Entry::word("uber", Is::A));
+        resolver.add_entry(Entry::case_sensitive("HMRC", Is::An));
+        resolver.add_entry(Entry::prefix("herbal", Is::A));
+
+        assert_eq!("a", resolver.get_a_or_an("uber"));
+        assert_eq!("A", resolver.get_a_or_an("Uber driver"));
+        assert_eq!("an", resolver.get_a_or_an("HMRC form"));
+        assert_eq!("a", resolver.get_a_or_an("herbalist"));
+        // not overridden
+        assert_eq!("an", resolver.get_a_or_an("herb"));
+        assert_eq!("an", resolver.get_a_or_an("umbrella"));
+
+        assert_eq!(
+            Reason::Override {
+                entry: Entry::word("uber", Is::A)
+            },
+            resolver.explain("Uber").reason
+        );
+    }
+
+    #[test]
+    fn entries_in_text() {
+        let resolver = Resolver::with_dictionary(
+            Options::default(),
+            Dictionary::with_entries(vec![Entry::word("uber", Is::A)]),
+        );
+
+        assert_eq!(
+            "a Uber driver, an umbrella",
+            resolver.fix_text("an Uber driver, a umbrella")
+        );
+    }
+
+    #[test]
+    fn symbols_are_read_out_by_name() {
+        let mut resolver = Resolver::new(Options::default());
+        resolver.add_symbol('#', "number");
+        resolver.add_symbol('\u{2603}', "snowman");
+
+        assert_eq!("a", resolver.get_a_or_an("#1 hit"));
+        assert_eq!("a", resolver.get_a_or_an("\u{2603}"));
+        assert_eq!("an", resolver.get_a_or_an("@mention"));
+        assert_eq!(
+            Reason::Spoken {
+                token: "@".to_string(),
+                spoken: "at".to_string()
+            },
+            resolver.explain("(@mention)").reason
+        );
+        assert_eq!(
+            "a #1 hit from an @artist with an \u{1F34E}",
+            resolver.fix_text("an #1 hit from a @artist with a \u{1F34E}")
+        );
+
+        let resolver = Resolver::new(Options::default()).with_symbols(Symbols::empty());
+        // without the table, the symbol is skipped
+        assert_eq!("a", resolver.get_a_or_an("@user"));
+        assert_eq!(Is::None, resolver.is_an("\u{03B1}-helix"));
+    }
+}
@@ -1,3 +1,66 @@
+pub mod pipeline;
 pub mod rule_acronyms;
+pub mod rule_ambiguous;
 pub mod rule_exceptions;
 pub mod rule_numbers;
+pub mod rule_roman_numerals;
+pub mod rule_technical;
+
+use crate::core_is_an::Is;
+use crate::decision::Reason;
+use crate::options::Options;
+
+/// A rule that may decide the article for the head token of a word.
+///
+/// Rules are run in order by a `Pipeline`: the first rule that returns `Some` decides the article.
+/// If no rule applies, then the general rule is used: 'an' for a word that starts with a vowel, else 'a'.
+///
+/// # Examples
+///
+/// ```
+/// use in_definite::{Is, Options, Resolver, Rule};
+///
+/// // 'SKU' is read out like 'skew'
+/// struct SkuRule;
+///
+/// impl Rule for SkuRule {
+///     fn name(&self) -> &str {
+///         "sku"
+///     }
+///
+///     fn apply(&self, head: &str, _options: &Options) -> Option<Is> {
+///         if head.starts_with("SKU") {
+///             return Some(Is::A);
+///         }
+///
+///         None
+///     }
+/// }
+///
+/// let mut resolver = Resolver::new(Options::default());
+/// assert_eq!("an", resolver.get_a_or_an("SKU"));
+///
+/// resolver.pipeline_mut().insert(0, SkuRule);
+/// assert_eq!("a", resolver.get_a_or_an("SKU"));
+/// ```
+pub trait Rule: Send + Sync {
+    /// The name of the rule, used to find it in a `Pipeline`.
+    fn name(&self) -> &str;
+
+    /// Returns the article for the given head token, or `None` if this rule does not apply.
+    fn apply(&self, head: &str, options: &Options) -> Option<Is>;
+
+    /// Returns the article for the given head token and the reason for it, or `None` if this rule does not apply.
+    ///
+    /// By default, the reason is `Reason::Rule` with the name of this rule.
+    fn explain(&self, head: &str, options: &Options) -> Option<(Is, Reason)> {
+        self.apply(head, options).map(|is| {
+            (
+                is,
+                Reason::Rule {
+                    name: self.name().to_string(),
+                },
+            )
+        })
+    }
+}
@@ -0,0 +1,210 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::core_is_an::Is;
+use crate::decision::Reason;
+use crate::options::Options;
+use crate::rules::rule_acronyms::AcronymRule;
+use crate::rules::rule_exceptions::ExceptionRule;
+use crate::rules::rule_numbers::NumberRule;
+use crate::rules::rule_roman_numerals::RomanNumeralRule;
+use crate::rules::rule_technical::TechnicalRule;
+use crate::rules::Rule;
+
+/// An ordered list of rules. The first rule that applies to a head token decides its article.
+///
+/// The default pipeline holds the built-in rules: "technical", "numbers", "roman_numerals", "acronyms" then "exceptions".
+#[derive(Clone)]
+pub struct Pipeline {
+    rules: Vec<Arc<dyn Rule>>,
+}
+
+impl Default for Pipeline {
+    fn default() -> Pipeline {
+        let mut pipeline = Pipeline::empty();
+
+        pipeline.push(TechnicalRule);
+        pipeline.push(NumberRule);
+        pipeline.push(RomanNumeralRule);
+        pipeline.push(AcronymRule);
+        pipeline.push(ExceptionRule);
+
+        pipeline
+    }
+}
+
+impl fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
+
+impl Pipeline {
+    /// A pipeline with no rules, so every word follows the general rule.
+    pub fn empty() -> Pipeline {
+        Pipeline { rules: Vec::new() }
+    }
+
+    /// Add a rule at the end of the pipeline.
+    pub fn push<R: Rule + 'static>(&mut self, rule: R) {
+        self.rules.push(Arc::new(rule));
+    }
+
+    /// Add a rule at the given position. Panics if `index` is greater than the number of rules.
+    pub fn insert<R: Rule + 'static>(&mut self, index: usize, rule: R) {
+        self.rules.insert(index, Arc::new(rule));
+    }
+
+    /// Remove the rule with the given name. Returns true if a rule was removed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        match self.position(name) {
+            Some(index) => {
+                self.rules.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Move the rule with the given name to the given position. Returns true if the rule was found.
+    ///
+    /// Panics if `index` is not less than the number of rules.
+    pub fn move_to(&mut self, name: &str, index: usize) -> bool {
+        match self.position(name) {
+            Some(current) => {
+                let rule = self.rules.remove(current);
+                self.rules.insert(index, rule);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The position of the rule with the given name.
+    pub fn position(&self, name: &str) -> Option<usize> {
+        self.rules.iter().position(|rule| rule.name() == name)
+    }
+
+    /// The names of the rules, in order.
+    pub fn names(&self) -> Vec<&str> {
+        self.rules.iter().map(|rule| rule.name()).collect()
+    }
+
+    /// Run the rules in order, returning the article of the first rule that applies.
+    pub fn apply(&self, head: &str, options: &Options) -> Option<Is> {
+        self.rules
+            .iter()
+            .filter_map(|rule| rule.apply(head, options))
+            .next()
+    }
+
+    /// Run the rules in order, returning the decision of the first rule that applies.
+    pub fn explain(&self, head: &str, options: &Options) -> Option<(Is, Reason)> {
+        self.rules
+            .iter()
+            .filter_map(|rule| rule.explain(head, options))
+            .next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 'SKU' is read out like 'skew'
+    struct SkuRule;
+
+    impl Rule for SkuRule {
+        fn name(&self) -> &str {
+            "sku"
+        }
+
+        fn apply(&self, head: &str, _options: &Options) -> Option<Is> {
+            if head.starts_with("SKU") {
+                return Some(Is::A);
+            }
+
+            None
+        }
+    }
+
+    #[test]
+    fn default_test() {
+        assert_eq!(
+            vec![
+                "technical",
+                "numbers",
+                "roman_numerals",
+                "acronyms",
+                "exceptions"
+            ],
+            Pipeline::default().names()
+        );
+    }
+
+    #[test]
+    fn insert_remove_and_move_test() {
+        let mut pipeline = Pipeline::default();
+
+        pipeline.insert(0, SkuRule);
+        assert_eq!(
+            vec![
+                "sku",
+                "technical",
+                "numbers",
+                "roman_numerals",
+                "acronyms",
+                "exceptions"
+            ],
+            pipeline.names()
+        );
+
+        assert!(pipeline.move_to("sku", 5));
+        assert_eq!(
+            vec![
+                "technical",
+                "numbers",
+                "roman_numerals",
+                "acronyms",
+                "exceptions",
+                "sku"
+            ],
+            pipeline.names()
+        );
+
+        assert!(pipeline.remove("acronyms"));
+        assert!(!pipeline.remove("acronyms"));
+        assert_eq!(Some(4), pipeline.position("sku"));
+        assert_eq!(None, pipeline.position("acronyms"));
+    }
+
+    #[test]
+    fn explain_test() {
+        let options = Options::default();
+        let mut pipeline = Pipeline::default();
+
+        // the acronym rule comes first
+        assert_eq!(
+            Some((
+                Is::An,
+                Reason::Acronym {
+                    irregular_letter: true
+                }
+            )),
+            pipeline.explain("SKU", &options)
+        );
+
+        pipeline.insert(0, SkuRule);
+        assert_eq!(
+            Some((
+                Is::A,
+                Reason::Rule {
+                    name: "sku".to_string()
+                }
+            )),
+            pipeline.explain("SKU", &options)
+        );
+
+        assert_eq!(None, Pipeline::empty().explain("heir", &options));
+    }
+}
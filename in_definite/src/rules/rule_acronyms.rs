@@ -1,7 +1,51 @@
+use crate::core_is_an;
+use crate::core_is_an::Is;
+use crate::decision::Reason;
+use crate::options::{AcronymMode, AllCapsMode, Options};
+use crate::rules::{rule_ambiguous, rule_exceptions, Rule};
 use crate::string_helper;
+use crate::utils::token_classifier::{self, TokenClass};
 
-pub fn is_acronym(word: &str) -> bool {
-    word.chars().all(|ch| ch.is_uppercase())
+/// The built-in rule for acronyms, named "acronyms". An acronym is read out letter by letter. For example: an FBI, a UFO.
+pub struct AcronymRule;
+
+impl Rule for AcronymRule {
+    fn name(&self) -> &str {
+        "acronyms"
+    }
+
+    fn apply(&self, head: &str, options: &Options) -> Option<Is> {
+        self.explain(head, options).map(|(is, _)| is)
+    }
+
+    fn explain(&self, head: &str, options: &Options) -> Option<(Is, Reason)> {
+        if is_read_as_word(head, options) {
+            return None;
+        }
+
+        let letters = match token_classifier::classify(head) {
+            TokenClass::Letters(letters) => letters.to_uppercase(),
+            _ => return None,
+        };
+
+        if options.acronyms == AcronymMode::Pronounced {
+            let is_listed = PRONOUNCED_ACRONYMS.contains(&letters.as_str());
+
+            if is_listed || is_pronounceable(&letters) {
+                return Some((
+                    core_is_an::to_is(is_an_for_pronounced_acronym(&letters)),
+                    Reason::PronouncedAcronym { listed: is_listed },
+                ));
+            }
+        }
+
+        Some((
+            core_is_an::to_is(is_an_for_acronym(&letters)),
+            Reason::Acronym {
+                irregular_letter: is_irregular_acronym(&letters),
+            },
+        ))
+    }
 }
 
 // ref: https://github.com/tandrewnichols/indefinite/blob/master/lib/rules/acronyms.js
@@ -20,14 +64,145 @@ pub fn is_an_for_acronym(word: &str) -> bool {
     true
 }
 
+/// Returns true if the given ALL CAPS word is read as an ordinary word, rather than as an acronym. See `Options::all_caps`.
+pub fn is_read_as_word(word: &str, options: &Options) -> bool {
+    if !string_helper::is_upper_case(word) || !word.chars().all(|ch| ch.is_alphabetic()) {
+        return false;
+    }
+
+    match options.all_caps {
+        AllCapsMode::Acronyms => false,
+        AllCapsMode::Words => !looks_like_initialism(word),
+        AllCapsMode::Auto => {
+            is_known_word(&word.to_lowercase(), options)
+                || (word.len() >= 4 && !looks_like_initialism(word))
+        }
+    }
+}
+
+// An ALL CAPS token that is read letter by letter, even in a sentence in ALL CAPS:
+// it has no vowel ('HTML'), or it starts with two consonants that cannot start an English word ('FBI', 'HVAC').
+fn looks_like_initialism(word: &str) -> bool {
+    let is_vowel = |ch: char| "AEIOUY".contains(ch);
+    if !word.chars().any(is_vowel) {
+        return true;
+    }
+
+    let start: String = word.chars().take(2).collect();
+    start.chars().count() == 2
+        && !start.chars().any(is_vowel)
+        && !STARTING_CONSONANTS.contains(&start.as_str())
+}
+
+fn is_known_word(word_lower: &str, options: &Options) -> bool {
+    rule_exceptions::find_exception(word_lower, options.dialect).is_some()
+        || rule_exceptions::find_exception_after_strip(word_lower, options.dialect).is_some()
+        || rule_ambiguous::is_ambiguous_word(word_lower)
+}
+
+// An acronym that is read as a word: 'a NASA', 'an OPEC', 'a UNICEF' ('you-nicef').
+fn is_an_for_pronounced_acronym(word: &str) -> bool {
+    string_helper::get_first_letter(word).map_or(false, |ch| "AEIO".contains(ch))
+}
+
+// A heuristic for whether an acronym can be pronounced as a word:
+// it has at least 4 letters, it alternates between vowels and consonants without long runs of either,
+// and it starts like an English word would.
+pub fn is_pronounceable(word: &str) -> bool {
+    if word.len() < 4 || !word.chars().all(|ch| ch.is_ascii_uppercase()) {
+        return false;
+    }
+
+    let is_vowel = |ch: char| "AEIOU".contains(ch);
+
+    if !word.chars().any(is_vowel) {
+        return false;
+    }
+
+    let mut run_of_vowels = 0;
+    let mut run_of_consonants = 0;
+    for ch in word.chars() {
+        if is_vowel(ch) {
+            run_of_vowels += 1;
+            run_of_consonants = 0;
+        } else {
+            run_of_consonants += 1;
+            run_of_vowels = 0;
+        }
+
+        if run_of_vowels > 2 || run_of_consonants > 2 {
+            return false;
+        }
+    }
+
+    let starts_with_two_consonants = word.chars().take(2).all(|ch| !is_vowel(ch));
+    !starts_with_two_consonants || STARTING_CONSONANTS.contains(&&word[..2])
+}
+
+// Pairs of consonants that can start an English word.
+const STARTING_CONSONANTS: &[&str] = &[
+    "BL", "BR", "CH", "CL", "CR", "DR", "FL", "FR", "GL", "GR", "PL", "PR", "SC", "SH", "SK", "SL",
+    "SM", "SN", "SP", "ST", "SW", "TH", "TR", "TW", "WH",
+];
+
+// Acronyms that are read as a word, including some that the heuristic does not detect.
+const PRONOUNCED_ACRONYMS: &[&str] = &[
+    "AIDS", "ASAP", "AWOL", "COVID", "FEMA", "FIFA", "IKEA", "LASER", "NAFTA", "NASA", "NATO",
+    "NIMBY", "OPEC", "OSHA", "RADAR", "SARS", "SCUBA", "UEFA", "UNESCO", "UNICEF",
+];
+
 fn both_or_neither(a: bool, b: bool) -> bool {
     a && b || !a && !b
 }
 
-fn is_irregular_acronym(word: &str) -> bool {
-    "UFHLMNRSX".contains(string_helper::get_first_letter(word))
+pub fn is_irregular_acronym(word: &str) -> bool {
+    string_helper::get_first_letter(word).map_or(false, |ch| "UFHLMNRSX".contains(ch))
 }
 
 fn starts_with_vowel(word: &str) -> bool {
-    "aeiouAEIOU".contains(string_helper::get_first_letter(word))
+    string_helper::get_first_letter(word).map_or(false, |ch| "aeiouAEIOU".contains(ch))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_pronounceable_test() {
+        assert!(is_pronounceable("NASA"));
+        assert!(is_pronounceable("SCUBA"));
+        assert!(is_pronounceable("CRUD"));
+        assert!(!is_pronounceable("FBI")); // too short
+        assert!(!is_pronounceable("HTTP")); // no vowel
+        assert!(!is_pronounceable("ISBN")); // long run of consonants
+        assert!(!is_pronounceable("IEEE")); // long run of vowels
+        assert!(!is_pronounceable("HVAC")); // unusual start
+        assert!(!is_pronounceable("MP3S"));
+    }
+
+    #[test]
+    fn looks_like_initialism_test() {
+        assert!(looks_like_initialism("FBI"));
+        assert!(looks_like_initialism("HTTPS"));
+        assert!(looks_like_initialism("HVAC"));
+        assert!(looks_like_initialism("F"));
+        assert!(!looks_like_initialism("UMBRELLA"));
+        assert!(!looks_like_initialism("AGENT"));
+        assert!(!looks_like_initialism("STRENGTH"));
+        assert!(!looks_like_initialism("HAT"));
+    }
+
+    #[test]
+    fn is_read_as_word_test() {
+        let auto = Options::with_all_caps(AllCapsMode::Auto);
+        let words = Options::with_all_caps(AllCapsMode::Words);
+
+        assert!(is_read_as_word("UMBRELLA", &auto));
+        assert!(is_read_as_word("HOUR", &auto));
+        assert!(!is_read_as_word("FBI", &auto));
+        assert!(!is_read_as_word("HAT", &auto)); // too short to tell, outside a sentence
+        assert!(is_read_as_word("HAT", &words));
+        assert!(!is_read_as_word("FBI", &words));
+        assert!(!is_read_as_word("UMBRELLA", &Options::default()));
+    }
 }
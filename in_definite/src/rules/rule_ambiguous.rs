@@ -0,0 +1,49 @@
+use crate::number_words;
+use crate::rules::{rule_exceptions, rule_numbers};
+use crate::string_helper;
+use crate::utils::token_classifier::{self, TokenClass};
+
+/// Returns true if both 'a' and 'an' are accepted for the given head token.
+pub fn is_ambiguous(head: &str) -> bool {
+    // a decade is always read out as a year: 'an 1800s house'
+    if number_words::year_to_words(head).is_some() {
+        return false;
+    }
+
+    if let TokenClass::Number(digits) = token_classifier::classify(head) {
+        return rule_numbers::is_colloquial_candidate(digits);
+    }
+
+    // plural acronyms like 'FAQs'
+    let acronym = string_helper::strip_end(head, "s");
+    if AMBIGUOUS_ACRONYMS.contains(&acronym) {
+        return true;
+    }
+
+    is_ambiguous_word(&string_helper::fold_accents(head).to_lowercase())
+}
+
+pub fn is_ambiguous_word(word_lower: &str) -> bool {
+    AMBIGUOUS_WORDS.contains(&word_lower)
+        || rule_exceptions::ENDINGS
+            .iter()
+            .map(|ending| string_helper::strip_end(word_lower, ending))
+            .any(|stripped| stripped != word_lower && AMBIGUOUS_WORDS.contains(&stripped))
+}
+
+// Acronyms that are either spelled out or read as a word: 'an F-A-Q' or 'a fak'.
+const AMBIGUOUS_ACRONYMS: &[&str] = &["FAQ", "LED", "SQL", "URL"];
+
+// Words with an 'h' that some speakers drop: 'a historic' or 'an historic'.
+const AMBIGUOUS_WORDS: &[&str] = &[
+    "habitual",
+    "herb",
+    "herbal",
+    "heroic",
+    "historian",
+    "historic",
+    "historical",
+    "hotel",
+    "hypothesis",
+    "hysterical",
+];
@@ -1,22 +1,93 @@
+use crate::core_is_an;
+use crate::core_is_an::Is;
+use crate::decision::Reason;
+use crate::options::{Dialect, Options};
+use crate::rules::Rule;
 use crate::string_helper;
 
-pub fn is_exception_after_strip(word_lower: &str) -> bool {
-    // into_iter():
-    // book: Rust 2018 says: If we want to create an iterator that takes ownership of v1 and returns owned values, we can call into_iter instead of iter.
-    ENDINGS
-        .into_iter()
-        .map(|ending| string_helper::strip_end(&word_lower, ending))
-        .any(|stripped| is_exception(stripped))
+/// The built-in rule for irregular words, named "exceptions". For example: an heir, a unicorn.
+pub struct ExceptionRule;
+
+impl Rule for ExceptionRule {
+    fn name(&self) -> &str {
+        "exceptions"
+    }
+
+    fn apply(&self, head: &str, options: &Options) -> Option<Is> {
+        self.explain(head, options).map(|(is, _)| is)
+    }
+
+    fn explain(&self, head: &str, options: &Options) -> Option<(Is, Reason)> {
+        let word_lower = head.to_lowercase();
+        let is_an = !core_is_an::is_naively_an(&word_lower);
+
+        if let Some(entry) = find_exception(&word_lower, options.dialect) {
+            return Some((
+                core_is_an::to_is(is_an),
+                Reason::Exception {
+                    entry: entry.to_string(),
+                },
+            ));
+        }
+
+        find_exception_after_strip(&word_lower, options.dialect).map(|(entry, suffix)| {
+            (
+                core_is_an::to_is(is_an),
+                Reason::StrippedException {
+                    entry: entry.to_string(),
+                    suffix: suffix.to_string(),
+                },
+            )
+        })
+    }
 }
 
-const ENDINGS: &[&str] = &["s", "es", "ed", "ly"];
+pub fn find_exception_after_strip(
+    word_lower: &str,
+    dialect: Dialect,
+) -> Option<(&'static str, &'static str)> {
+    ENDINGS.iter().find_map(|ending| {
+        let stripped = string_helper::strip_end(word_lower, ending);
+        if stripped == word_lower {
+            return None;
+        }
 
-pub fn is_exception(word: &str) -> bool {
+        find_exception(stripped, dialect).map(|entry| (entry, *ending))
+    })
+}
+
+// Suffixes that are stripped to find a word in a list: 'heirs', 'honoured', 'hourly'
+pub(crate) const ENDINGS: &[&str] = &["s", "es", "ed", "ly"];
+
+pub fn find_exception(word: &str, dialect: Dialect) -> Option<&'static str> {
     // ref: https://github.com/tandrewnichols/indefinite/blob/master/lib/irregular-words.js
 
-    EXCEPTIONS.contains(&word)
+    EXCEPTIONS
+        .iter()
+        .find(|entry| **entry == word)
+        .or_else(|| {
+            DIALECT_EXCEPTIONS
+                .iter()
+                .find(|(entry, dialects)| *entry == word && dialects.contains(&dialect))
+                .map(|(entry, _)| entry)
+        })
+        .copied()
 }
 
+// Words that are exceptions only in some dialects.
+const DIALECT_EXCEPTIONS: &[(&str, &[Dialect])] = &[
+    // Nouns: silent h, in American English only
+    ("herb", &[Dialect::Us]),
+    // Adjectives: silent h, in American English only
+    ("herbal", &[Dialect::Us]),
+    ("herbalist", &[Dialect::Us]),
+    // Nouns: silent h, as preferred in British and Irish English
+    ("hotel", &[Dialect::Uk, Dialect::Ie]),
+    // Adjectives: silent h, as preferred in British and Irish English
+    ("historic", &[Dialect::Uk, Dialect::Ie]),
+    ("historical", &[Dialect::Uk, Dialect::Ie]),
+];
+
 const EXCEPTIONS: &[&str] = &[
     // Nouns: eu like y
     "eunuch",
@@ -40,7 +111,6 @@ const EXCEPTIONS: &[&str] = &[
     // Nouns: silent h
     "heir",
     "heiress",
-    "herb",
     "homage",
     "honesty",
     "honor",
@@ -56,6 +126,11 @@ const EXCEPTIONS: &[&str] = &[
     // Adverbs: silent h
     "honestly",
     "hourly",
+    // Nouns: silent h, from French (accents are folded: 'habitué' is 'habitue')
+    "habitue",
+    "hauteur",
+    "hommage",
+    "hors",
     // Nouns: o like w
     "one",
     "ouija",
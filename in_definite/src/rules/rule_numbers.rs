@@ -1,27 +1,126 @@
+use crate::core_is_an;
+use crate::core_is_an::Is;
+use crate::decision::Reason;
+use crate::number_words;
 use crate::options::Options;
+use crate::rules::rule_exceptions;
+use crate::rules::Rule;
 use crate::string_helper;
+use crate::utils::token_classifier::{self, TokenClass};
 
-// ref: https://github.com/tandrewnichols/indefinite/blob/master/lib/rules/numbers.js
-pub fn is_number(word: &str) -> bool {
-    string_helper::get_first_letter(word).is_numeric()
+/// The built-in rule for numbers, named "numbers". For example: an 8, a 10, an 18.
+pub struct NumberRule;
+
+impl Rule for NumberRule {
+    fn name(&self) -> &str {
+        "numbers"
+    }
+
+    fn apply(&self, head: &str, options: &Options) -> Option<Is> {
+        self.explain(head, options).map(|(is, _)| is)
+    }
+
+    fn explain(&self, head: &str, options: &Options) -> Option<(Is, Reason)> {
+        // full-width or Arabic-Indic digits are read as ASCII digits: '８' is '8'
+        let normalized = number_words::normalize_digits(head);
+        let mut head: &str = &normalized;
+
+        if let Some(spoken) = number_words::year_to_words(head) {
+            let is_an = core_is_an::to_is(is_an_for_words(&spoken, options)?);
+            return Some((is_an, Reason::Year { spoken }));
+        }
+
+        // a currency symbol is read out after the number: '$8' is 'eight dollars'
+        // a sign is read out first: '-8' is 'minus eight'
+        // an apostrophe is not read out: "'8'" is 'eight'
+        while let Some(first) = head.chars().next() {
+            if string_helper::is_currency_symbol(first) || string_helper::is_apostrophe(first) {
+                head = &head[first.len_utf8()..];
+                continue;
+            }
+
+            if string_helper::is_sign(first)
+                && head.chars().nth(1).map_or(false, |ch| {
+                    ch.is_numeric() || string_helper::is_currency_symbol(ch)
+                })
+            {
+                let spoken = spoken_sign(first);
+                return Some((
+                    core_is_an::to_is(core_is_an::is_naively_an(spoken)),
+                    Reason::Spoken {
+                        token: first.to_string(),
+                        spoken: spoken.to_string(),
+                    },
+                ));
+            }
+
+            break;
+        }
+
+        let number = match token_classifier::classify(head) {
+            TokenClass::Number(number) => number,
+            _ => return None,
+        };
+
+        // a fraction, a range, a time, a number with a unit, or in hex or scientific notation: '1/8', '8–10', '8:30', '8kg', '0x8F', '8e10'
+        // else only the number is read out: '18' for '18K'
+        let mut words = number_words::number_to_words(head, options);
+        if words.is_empty() {
+            words = number_words::number_to_words(number, options);
+        }
+        if words.is_empty() {
+            // not a number in the locale, like "8'000" in the US: read out the leading digits
+            let digits = number
+                .find(|ch: char| !ch.is_numeric())
+                .map_or(number, |end| &number[..end]);
+            words = number_words::number_to_words(digits, options);
+        }
+
+        let is_an = core_is_an::to_is(is_an_for_words(&words, options)?);
+        let colloquial = is_colloquial_number(number, options);
+
+        if is_ordinal(head, number) {
+            return Some((is_an, Reason::Ordinal { colloquial }));
+        }
+
+        Some((is_an, Reason::Number { colloquial }))
+    }
 }
 
-pub fn is_an_for_number(word: &str, options: &Options) -> bool {
-    let mut is_an = false;
-
-    // If the number starts with 11 or 18 and is of length 4,
-    // the pronunciation is ambiguous so check opts.numbers to see
-    // how to render it. Otherwise, if it starts with 11 or 18
-    // and has 2, 5, 8, 11, etc. digits, use 'an.' Finally, if it
-    // starts with an 8, use 'an.' For everything else, use 'a.'
-    let starts_with_11_or_18 = word.starts_with("11") || word.starts_with("18");
-    if starts_with_11_or_18 && word.len() == 4 {
-        is_an = options.are_numbers_colloquial;
-    } else if starts_with_11_or_18 && (word.len() - 2) % 3 == 0 {
-        is_an = true;
-    } else if word.starts_with('8') {
-        is_an = true;
+fn spoken_sign(sign: char) -> &'static str {
+    match sign {
+        '+' => "plus",
+        '\u{00B1}' => "plus or minus",
+        _ => "minus",
     }
+}
+
+// An ordinal number like '1st', '2nd', '3rd' or '18th': the suffix does not change how the number starts.
+fn is_ordinal(head: &str, digits: &str) -> bool {
+    let suffix = head[digits.len()..].to_lowercase();
+
+    number_words::ORDINAL_SUFFIXES.contains(&suffix.as_str())
+}
+
+pub fn is_colloquial_number(word: &str, options: &Options) -> bool {
+    options.are_numbers_colloquial && is_colloquial_candidate(word)
+}
+
+// A 4 digit number starting with 11 or 18 can be read both ways: 'eighteen hundred' or 'one thousand eight hundred'.
+pub fn is_colloquial_candidate(word: &str) -> bool {
+    word.len() == 4
+        && word.chars().all(|ch| ch.is_ascii_digit())
+        && (word.starts_with("11") || word.starts_with("18"))
+}
+
+// The article for the first word of a spelled out number: 'an eight', 'an eleven', 'a one'.
+pub fn is_an_for_words(words: &str, options: &Options) -> Option<bool> {
+    let first_word = words.split(|ch| ch == ' ' || ch == '-').next()?;
+    if first_word.is_empty() {
+        return None;
+    }
+
+    let is_exception = rule_exceptions::find_exception(first_word, options.dialect).is_some();
 
-    is_an
+    Some(core_is_an::is_naively_an(first_word) != is_exception)
 }
@@ -0,0 +1,135 @@
+use crate::core_is_an;
+use crate::core_is_an::Is;
+use crate::decision::Reason;
+use crate::number_words;
+use crate::options::Options;
+use crate::rules::rule_numbers;
+use crate::rules::Rule;
+
+/// The built-in rule for Roman numerals, named "roman_numerals". A Roman numeral is read out as a number. For example: an XI, an VIII, a LVIII.
+///
+/// The rule only applies if `Options::roman_numerals` is true.
+pub struct RomanNumeralRule;
+
+impl Rule for RomanNumeralRule {
+    fn name(&self) -> &str {
+        "roman_numerals"
+    }
+
+    fn apply(&self, head: &str, options: &Options) -> Option<Is> {
+        self.explain(head, options).map(|(is, _)| is)
+    }
+
+    fn explain(&self, head: &str, options: &Options) -> Option<(Is, Reason)> {
+        if !options.roman_numerals {
+            return None;
+        }
+
+        let value = parse_roman_numeral(head)?;
+        let words = number_words::number_to_words(&value.to_string(), options);
+        let is_an = core_is_an::to_is(rule_numbers::is_an_for_words(&words, options)?);
+
+        Some((is_an, Reason::RomanNumeral { value }))
+    }
+}
+
+const NUMERALS: [(&str, u32); 13] = [
+    ("M", 1000),
+    ("CM", 900),
+    ("D", 500),
+    ("CD", 400),
+    ("C", 100),
+    ("XC", 90),
+    ("L", 50),
+    ("XL", 40),
+    ("X", 10),
+    ("IX", 9),
+    ("V", 5),
+    ("IV", 4),
+    ("I", 1),
+];
+
+// Valid numerals that are more often words or acronyms: 'a MIX', 'a CD', 'an MD', 'an XL shirt'.
+const NOT_NUMERALS: &[&str] = &[
+    "CC", "CD", "CI", "CV", "DC", "DI", "DIV", "LI", "MC", "MD", "MI", "MIX", "MM", "XL",
+];
+
+// A Roman numeral in UPPER case, of at least 2 letters and in the standard form: 'XI', 'VIII', 'MCM'.
+// A single letter like 'I' is not a numeral.
+pub fn parse_roman_numeral(word: &str) -> Option<u32> {
+    if word.len() < 2 || NOT_NUMERALS.contains(&word) {
+        return None;
+    }
+
+    let mut value = 0;
+    let mut rest = word;
+    for (numeral, numeral_value) in NUMERALS.iter() {
+        while rest.starts_with(numeral) {
+            rest = &rest[numeral.len()..];
+            value += numeral_value;
+        }
+    }
+
+    // 'IIII', 'VV' and 'MMMM' are not in the standard form
+    if !rest.is_empty() || value >= 4000 || to_roman_numeral(value) != word {
+        return None;
+    }
+
+    Some(value)
+}
+
+fn to_roman_numeral(mut value: u32) -> String {
+    let mut numeral = String::new();
+    for (letters, letters_value) in NUMERALS.iter() {
+        while value >= *letters_value {
+            numeral.push_str(letters);
+            value -= letters_value;
+        }
+    }
+
+    numeral
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_roman_numeral_test() {
+        assert_eq!(Some(2), parse_roman_numeral("II"));
+        assert_eq!(Some(4), parse_roman_numeral("IV"));
+        assert_eq!(Some(8), parse_roman_numeral("VIII"));
+        assert_eq!(Some(11), parse_roman_numeral("XI"));
+        assert_eq!(Some(58), parse_roman_numeral("LVIII"));
+        assert_eq!(Some(1900), parse_roman_numeral("MCM"));
+        assert_eq!(Some(3999), parse_roman_numeral("MMMCMXCIX"));
+        // not in the standard form
+        assert_eq!(None, parse_roman_numeral("IIII"));
+        assert_eq!(None, parse_roman_numeral("VV"));
+        assert_eq!(None, parse_roman_numeral("IC"));
+        assert_eq!(None, parse_roman_numeral("XM"));
+        assert_eq!(None, parse_roman_numeral("MMMM"));
+        // not numerals
+        assert_eq!(None, parse_roman_numeral("I"));
+        assert_eq!(None, parse_roman_numeral("X"));
+        assert_eq!(None, parse_roman_numeral("MIX"));
+        assert_eq!(None, parse_roman_numeral("xi"));
+        assert_eq!(None, parse_roman_numeral("FBI"));
+        assert_eq!(None, parse_roman_numeral(""));
+    }
+
+    #[test]
+    fn explain_test() {
+        let options = Options::with_roman_numerals();
+
+        assert_eq!(
+            Some((Is::An, Reason::RomanNumeral { value: 11 })),
+            RomanNumeralRule.explain("XI", &options)
+        );
+        assert_eq!(
+            Some((Is::A, Reason::RomanNumeral { value: 58 })),
+            RomanNumeralRule.explain("LVIII", &options)
+        );
+        assert_eq!(None, RomanNumeralRule.explain("XI", &Options::default()));
+    }
+}
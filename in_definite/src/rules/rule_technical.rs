@@ -0,0 +1,363 @@
+use crate::core_is_an;
+use crate::core_is_an::Is;
+use crate::decision::Reason;
+use crate::number_words;
+use crate::options::{ExtensionStyle, Options};
+use crate::rules::rule_numbers;
+use crate::rules::Rule;
+
+/// The built-in rule for technical tokens, named "technical". A URL, an e-mail address, a domain name, a file extension or a path is read out as spoken.
+/// For example: an https://example.com ('aitch'), a www.example.com ('double-u'), an x@y.com ('ex'), a /etc path ('slash'), an .exe file.
+///
+/// How a file extension is read out depends on `Options::extensions`.
+pub struct TechnicalRule;
+
+impl Rule for TechnicalRule {
+    fn name(&self) -> &str {
+        "technical"
+    }
+
+    fn apply(&self, head: &str, options: &Options) -> Option<Is> {
+        self.explain(head, options).map(|(is, _)| is)
+    }
+
+    fn explain(&self, head: &str, options: &Options) -> Option<(Is, Reason)> {
+        let spoken = technical_to_words(head, options)?;
+        let is_an = core_is_an::to_is(rule_numbers::is_an_for_words(&spoken, options)?);
+
+        Some((is_an, Reason::Technical { spoken }))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Technical<'t> {
+    // 'https://example.com': the scheme is read out
+    Url { scheme: &'t str },
+    // 'x@y.com': the start of the local part is read out
+    Email { local: &'t str },
+    // 'www.example.com': the first label is read out
+    Domain { label: &'t str },
+    // '.com' is read as 'dot com'
+    TopLevelDomain { label: &'t str },
+    // '.exe', '.tar.gz'
+    Extension { extension: &'t str },
+    // '/etc', '~/bin', './run.sh', '\\server\share'
+    Path { spoken: &'static str },
+    // 'C:\Windows'
+    Drive { letter: char },
+}
+
+// Common top-level domains, that are read as 'dot com' rather than as a file extension.
+// Country codes that are also common file extensions, like '.sh', '.py', '.rs' and '.md', are left out.
+const TOP_LEVEL_DOMAINS: &[&str] = &[
+    "ai", "app", "au", "biz", "ca", "co", "com", "de", "dev", "edu", "eu", "fr", "gov", "info",
+    "int", "io", "jp", "me", "mil", "net", "org", "tv", "uk", "us", "xyz",
+];
+
+const PATH_STARTS: &[(&str, &str)] = &[
+    ("../", "dot dot"),
+    ("./", "dot"),
+    ("~/", "tilde"),
+    ("\\", "backslash"),
+    ("/", "slash"),
+];
+
+const LETTER_NAMES: [&str; 26] = [
+    "a", "bee", "see", "dee", "e", "ef", "gee", "aitch", "i", "jay", "kay", "el", "em", "en", "o",
+    "pee", "cue", "ar", "es", "tee", "you", "vee", "double-u", "ex", "why", "zee",
+];
+
+/// The length in bytes of the URL, e-mail address, domain name, file extension or path at the start of the text, if any.
+/// Punctuation at the end, like a full stop, is not part of it.
+pub fn technical_token_len(text: &str) -> Option<usize> {
+    let end = text.find(is_token_end).unwrap_or(text.len());
+    let token = text[..end].trim_end_matches(|ch: char| ch == '.' || ch == ':');
+
+    classify(token).map(|_| token.len())
+}
+
+// How the start of a technical token is read out: 'aitch tee tee pee es' for 'https://example.com'
+pub fn technical_to_words(token: &str, options: &Options) -> Option<String> {
+    let words = match classify(token)? {
+        Technical::Url { scheme } => part_to_words(scheme, false, options),
+        Technical::Email { local } => {
+            let name = local
+                .split(|ch: char| !ch.is_ascii_alphanumeric())
+                .find(|name| !name.is_empty())?;
+            part_to_words(name, false, options)
+        }
+        Technical::Domain { label } => part_to_words(label, false, options),
+        Technical::TopLevelDomain { label } => {
+            format!("dot {}", part_to_words(label, false, options))
+        }
+        Technical::Extension { extension } => part_to_words(
+            extension,
+            options.extensions == ExtensionStyle::Letters,
+            options,
+        ),
+        Technical::Path { spoken } => spoken.to_string(),
+        Technical::Drive { letter } => letters_to_words(&letter.to_string(), options),
+    };
+
+    Some(words)
+}
+
+fn classify(token: &str) -> Option<Technical<'_>> {
+    if let Some(scheme_end) = token.find("://") {
+        let scheme = &token[..scheme_end];
+        let is_scheme = scheme.starts_with(|ch: char| ch.is_ascii_alphabetic())
+            && scheme
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || "+-.".contains(ch));
+
+        return if is_scheme && has_alphanumeric(&token[scheme_end..]) {
+            Some(Technical::Url { scheme })
+        } else {
+            None
+        };
+    }
+
+    if let Some((_, spoken)) = PATH_STARTS
+        .iter()
+        .find(|(start, _)| token.starts_with(start))
+    {
+        return if has_alphanumeric(token) {
+            Some(Technical::Path { spoken })
+        } else {
+            None
+        };
+    }
+
+    let mut chars = token.chars();
+    if let (Some(letter), Some(':'), Some(separator)) = (chars.next(), chars.next(), chars.next()) {
+        if letter.is_ascii_alphabetic() && (separator == '\\' || separator == '/') {
+            return Some(Technical::Drive { letter });
+        }
+    }
+
+    if token.starts_with('.') {
+        let extension = token[1..].split('.').next()?;
+        if !token[1..].split('.').all(is_extension) {
+            return None;
+        }
+
+        if TOP_LEVEL_DOMAINS.contains(&extension.to_lowercase().as_str()) {
+            return Some(Technical::TopLevelDomain { label: extension });
+        }
+
+        return Some(Technical::Extension { extension });
+    }
+
+    if let Some(at) = token.find('@') {
+        let local = &token[..at];
+        let is_local = !local.is_empty()
+            && local
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || "._%+-".contains(ch));
+
+        return if is_local && is_domain(&token[at + 1..], true) {
+            Some(Technical::Email { local })
+        } else {
+            None
+        };
+    }
+
+    let host = token.split(|ch| ch == '/' || ch == ':').next()?;
+    if is_domain(host, false) {
+        return host
+            .split('.')
+            .next()
+            .map(|label| Technical::Domain { label });
+    }
+
+    None
+}
+
+// 'example.com', 'EXAMPLE.COM' or 'www.example': the last label is a known top-level domain, in any case, or the first label is 'www'
+// For an e-mail address, any top-level domain of letters is accepted.
+fn is_domain(host: &str, is_any_top_level_domain: bool) -> bool {
+    let labels: Vec<&str> = host.split('.').collect();
+    if labels.len() < 2 || !labels.iter().all(|label| is_label(label)) {
+        return false;
+    }
+
+    let last = labels[labels.len() - 1];
+    if is_any_top_level_domain {
+        return last.len() >= 2 && last.chars().all(|ch| ch.is_ascii_alphabetic());
+    }
+
+    TOP_LEVEL_DOMAINS.contains(&last.to_ascii_lowercase().as_str())
+        || labels[0].eq_ignore_ascii_case("www")
+}
+
+fn is_label(label: &str) -> bool {
+    !label.is_empty()
+        && label
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '-')
+}
+
+// 'exe', 'mp3' or 'gz', but not '8'
+fn is_extension(extension: &str) -> bool {
+    !extension.is_empty()
+        && extension.len() <= 8
+        && extension.chars().all(|ch| ch.is_ascii_alphanumeric())
+        && extension.chars().any(|ch| ch.is_ascii_alphabetic())
+}
+
+fn has_alphanumeric(text: &str) -> bool {
+    text.chars().any(|ch| ch.is_alphanumeric())
+}
+
+fn is_token_end(ch: char) -> bool {
+    ch.is_whitespace()
+        || "\"'`()[]{}<>,;!?*|\u{2018}\u{2019}\u{201C}\u{201D}\u{200B}\u{2060}\u{FEFF}".contains(ch)
+}
+
+// A part is read as a word if it can be, else letter by letter: 'example', 'https' ('aitch tee tee pee es'), 'mp3'
+fn part_to_words(part: &str, is_read_as_letters: bool, options: &Options) -> String {
+    let digits_end = part
+        .find(|ch: char| !ch.is_ascii_digit())
+        .unwrap_or(part.len());
+    if digits_end > 0 {
+        return number_words::number_to_words(&part[..digits_end], options);
+    }
+
+    let is_acronym = part.chars().count() > 1 && part.chars().all(|ch| ch.is_ascii_uppercase());
+    let has_vowel = part.chars().any(|ch| "aeiouyAEIOUY".contains(ch));
+
+    if is_read_as_letters || is_acronym || part.chars().count() == 1 || !has_vowel {
+        return letters_to_words(part, options);
+    }
+
+    part.to_lowercase()
+}
+
+fn letters_to_words(letters: &str, options: &Options) -> String {
+    let words: Vec<String> = letters
+        .chars()
+        .filter_map(|ch| {
+            if ch.is_ascii_digit() {
+                return Some(number_words::number_to_words(&ch.to_string(), options));
+            }
+
+            let index = (ch.to_ascii_lowercase() as usize).checked_sub('a' as usize)?;
+            LETTER_NAMES.get(index).map(|name| name.to_string())
+        })
+        .collect();
+
+    words.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_words(token: &str) -> Option<String> {
+        technical_to_words(token, &Options::default())
+    }
+
+    #[test]
+    fn technical_token_len_test() {
+        assert_eq!(Some(19), technical_token_len("https://example.com, or"));
+        assert_eq!(Some(15), technical_token_len("www.example.com."));
+        assert_eq!(Some(7), technical_token_len("x@y.com address"));
+        assert_eq!(Some(4), technical_token_len("/etc)"));
+        assert_eq!(Some(4), technical_token_len(".exe file"));
+        assert_eq!(Some(10), technical_token_len("C:\\Windows"));
+        assert_eq!(None, technical_token_len("umbrella"));
+        assert_eq!(None, technical_token_len(".8"));
+        assert_eq!(None, technical_token_len("..."));
+        assert_eq!(None, technical_token_len("e.g."));
+        assert_eq!(None, technical_token_len("U.K."));
+        assert_eq!(None, technical_token_len("1/8"));
+        assert_eq!(None, technical_token_len("8:30"));
+        assert_eq!(None, technical_token_len("@mention"));
+        assert_eq!(None, technical_token_len("~~hour~~"));
+        assert_eq!(None, technical_token_len("/"));
+    }
+
+    #[test]
+    fn technical_to_words_test() {
+        let words = |token: &str| to_words(token).unwrap_or_default();
+
+        // URLs
+        assert_eq!("aitch tee tee pee es", words("https://example.com"));
+        assert_eq!("ef tee pee", words("ftp://example.com"));
+        assert_eq!("file", words("file:///etc/hosts"));
+        // e-mail addresses
+        assert_eq!("ex", words("x@y.com"));
+        assert_eq!("user", words("user@example.com"));
+        assert_eq!("john", words("john.smith@example.co.uk"));
+        // domain names
+        assert_eq!("double-u double-u double-u", words("www.example.com"));
+        assert_eq!("example", words("example.com/about"));
+        assert_eq!("example", words("Example.Com"));
+        assert_eq!("e ex a em pee el e", words("EXAMPLE.COM"));
+        assert_eq!("dot com", words(".com"));
+        assert_eq!("dot en e tee", words(".NET"));
+        // file extensions
+        assert_eq!("exe", words(".exe"));
+        assert_eq!("tar", words(".tar.gz"));
+        assert_eq!("aitch tee em el", words(".html"));
+        assert_eq!("em pee three", words(".mp3"));
+        assert_eq!("pee dee ef", words(".PDF"));
+        // paths
+        assert_eq!("slash", words("/etc"));
+        assert_eq!("tilde", words("~/bin"));
+        assert_eq!("dot", words("./run.sh"));
+        assert_eq!("dot dot", words("../lib"));
+        assert_eq!("backslash", words("\\\\server\\share"));
+        assert_eq!("see", words("C:\\Windows"));
+        assert_eq!("ef", words("F:/"));
+        // not technical
+        assert_eq!(None, to_words("umbrella"));
+        assert_eq!(None, to_words("readme.txt"));
+        assert_eq!(None, to_words("1.8"));
+        assert_eq!(None, to_words("@mention"));
+    }
+
+    #[test]
+    fn extension_style_test() {
+        let letters = Options::with_extensions(ExtensionStyle::Letters);
+
+        assert_eq!(Some("mov".to_string()), to_words(".mov"));
+        assert_eq!(
+            Some("em o vee".to_string()),
+            technical_to_words(".mov", &letters)
+        );
+        assert_eq!(
+            Some("dot com".to_string()),
+            technical_to_words(".com", &letters)
+        );
+    }
+
+    #[test]
+    fn explain_test() {
+        let options = Options::default();
+
+        assert_eq!(
+            Some((
+                Is::A,
+                Reason::Technical {
+                    spoken: "slash".to_string()
+                }
+            )),
+            TechnicalRule.explain("/etc", &options)
+        );
+        assert_eq!(Some(Is::An), TechnicalRule.apply(".exe", &options));
+        assert_eq!(
+            Some(Is::An),
+            TechnicalRule.apply("https://example.com", &options)
+        );
+        assert_eq!(
+            Some(Is::A),
+            TechnicalRule.apply("www.example.com", &options)
+        );
+        assert_eq!(
+            Some(Is::A),
+            TechnicalRule.apply("user@example.com", &options)
+        );
+        assert_eq!(None, TechnicalRule.apply("umbrella", &options));
+    }
+}
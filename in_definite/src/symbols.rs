@@ -0,0 +1,261 @@
+/// A table of symbols that are read out by name, when a word starts with one: 'an @-mention' ('at'), 'an α-helix' ('alpha').
+///
+/// The built-in table holds Greek letters, common mathematical symbols, the symbols '@', '&', '#', '%' and '+', and a
+/// small set of emoji. Symbols can be added, or renamed, or removed.
+///
+/// # Examples
+///
+/// ```
+/// use in_definite::{Options, Resolver, Symbols};
+///
+/// let mut symbols = Symbols::new();
+/// symbols.add('#', "number"); // '#1' as 'number one', rather than 'hash one'
+/// symbols.add('\u{2603}', "snowman");
+///
+/// let resolver = Resolver::new(Options::default()).with_symbols(symbols);
+///
+/// assert_eq!("an", resolver.get_a_or_an("@-mention"));
+/// assert_eq!("a", resolver.get_a_or_an("#1 hit"));
+/// assert_eq!("a", resolver.get_a_or_an("\u{2603}"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Symbols {
+    is_built_in: bool,
+    added: Vec<(char, String)>,
+    removed: Vec<char>,
+}
+
+impl Default for Symbols {
+    fn default() -> Symbols {
+        Symbols::new()
+    }
+}
+
+impl Symbols {
+    /// The built-in table of symbols.
+    pub fn new() -> Symbols {
+        Symbols {
+            is_built_in: true,
+            added: Vec::new(),
+            removed: Vec::new(),
+        }
+    }
+
+    /// A table with no symbols, so no symbol is read out by name.
+    pub fn empty() -> Symbols {
+        Symbols {
+            is_built_in: false,
+            ..Symbols::new()
+        }
+    }
+
+    /// Add a symbol, or replace how a symbol is read out.
+    pub fn add(&mut self, symbol: char, spoken: &str) {
+        self.removed.retain(|removed| *removed != symbol);
+        self.added.retain(|(added, _)| *added != symbol);
+        self.added.push((symbol, spoken.to_string()));
+    }
+
+    /// Remove a symbol, so it is no longer read out by name. Returns false if the symbol was not in the table.
+    pub fn remove(&mut self, symbol: char) -> bool {
+        if self.spoken(symbol).is_none() {
+            return false;
+        }
+
+        self.added.retain(|(added, _)| *added != symbol);
+        self.removed.push(symbol);
+
+        true
+    }
+
+    /// How the given symbol is read out, if it is in the table.
+    pub fn spoken(&self, symbol: char) -> Option<&str> {
+        if let Some((_, spoken)) = self.added.iter().find(|(added, _)| *added == symbol) {
+            return Some(spoken);
+        }
+
+        if !self.is_built_in || self.removed.contains(&symbol) {
+            return None;
+        }
+
+        GREEK_LETTERS
+            .iter()
+            .chain(MATH_SYMBOLS.iter())
+            .chain(TEXT_SYMBOLS.iter())
+            .chain(EMOJI.iter())
+            .find(|(symbols, _)| symbols.contains(symbol))
+            .map(|(_, spoken)| *spoken)
+    }
+
+    /// The symbol at the start of the head token, and how it is read out.
+    ///
+    /// A word in Greek, like 'αβγ', is not read out letter by letter.
+    pub fn find(&self, head: &str) -> Option<(char, &str)> {
+        let mut chars = head.chars();
+        let symbol = chars.next()?;
+        let spoken = self.spoken(symbol)?;
+
+        if is_greek_letter(symbol) && chars.next().map_or(false, is_greek_letter) {
+            return None;
+        }
+
+        Some((symbol, spoken))
+    }
+}
+
+fn is_greek_letter(ch: char) -> bool {
+    ch.is_alphabetic() && ('\u{0370}'..='\u{03FF}').contains(&ch)
+}
+
+// Greek letters, in lower and upper case, with their variant forms.
+const GREEK_LETTERS: &[(&str, &str)] = &[
+    ("\u{03B1}\u{0391}", "alpha"),
+    ("\u{03B2}\u{0392}\u{03D0}", "beta"),
+    ("\u{03B3}\u{0393}", "gamma"),
+    ("\u{03B4}\u{0394}", "delta"),
+    ("\u{03B5}\u{0395}\u{03F5}", "epsilon"),
+    ("\u{03B6}\u{0396}", "zeta"),
+    ("\u{03B7}\u{0397}", "eta"),
+    ("\u{03B8}\u{0398}\u{03D1}", "theta"),
+    ("\u{03B9}\u{0399}", "iota"),
+    ("\u{03BA}\u{039A}\u{03F0}", "kappa"),
+    ("\u{03BB}\u{039B}", "lambda"),
+    ("\u{03BC}\u{039C}\u{00B5}", "mu"),
+    ("\u{03BD}\u{039D}", "nu"),
+    ("\u{03BE}\u{039E}", "xi"),
+    ("\u{03BF}\u{039F}", "omicron"),
+    ("\u{03C0}\u{03A0}\u{03D6}", "pi"),
+    ("\u{03C1}\u{03A1}\u{03F1}", "rho"),
+    ("\u{03C3}\u{03C2}\u{03A3}", "sigma"),
+    ("\u{03C4}\u{03A4}", "tau"),
+    ("\u{03C5}\u{03A5}", "upsilon"),
+    ("\u{03C6}\u{03A6}\u{03D5}", "phi"),
+    ("\u{03C7}\u{03A7}", "chi"),
+    ("\u{03C8}\u{03A8}", "psi"),
+    ("\u{03C9}\u{03A9}", "omega"),
+];
+
+// Mathematical symbols. Signs before a number and currency symbols are read out by the number rule.
+const MATH_SYMBOLS: &[(&str, &str)] = &[
+    ("\u{221E}", "infinity"),
+    ("\u{2126}", "ohm"),
+    ("\u{2135}", "aleph"),
+    ("\u{210F}", "h-bar"),
+    ("\u{2113}", "ell"),
+    ("\u{2202}", "partial"),
+    ("\u{2207}", "nabla"),
+    ("\u{2211}", "sum"),
+    ("\u{220F}", "product"),
+    ("\u{221A}", "square root"),
+    ("\u{222B}", "integral"),
+    ("\u{2200}", "for all"),
+    ("\u{2203}", "exists"),
+    ("\u{2205}", "empty set"),
+    ("\u{2208}", "element of"),
+    ("\u{2229}", "intersection"),
+    ("\u{222A}", "union"),
+    ("\u{2220}", "angle"),
+    ("\u{22A5}", "perpendicular"),
+    ("\u{2248}", "approximately equal"),
+    ("\u{2260}", "not equal"),
+    ("\u{2264}", "less than or equal"),
+    ("\u{2265}", "greater than or equal"),
+    ("\u{00B0}", "degree"),
+];
+
+// Symbols in text, social media and markup: '@mention', '#hashtag', '&amp;'
+const TEXT_SYMBOLS: &[(&str, &str)] = &[
+    ("@", "at"),
+    ("&", "and"),
+    ("#", "hash"),
+    ("%", "percent"),
+    ("+", "plus"),
+];
+
+// A compact set of common emoji, by their short names.
+const EMOJI: &[(&str, &str)] = &[
+    ("\u{1F34E}", "apple"),
+    ("\u{1F34A}", "orange"),
+    ("\u{1F951}", "avocado"),
+    ("\u{1F346}", "eggplant"),
+    ("\u{1F95A}", "egg"),
+    ("\u{1F34C}", "banana"),
+    ("\u{1F355}", "pizza"),
+    ("\u{1F418}", "elephant"),
+    ("\u{1F989}", "owl"),
+    ("\u{1F419}", "octopus"),
+    ("\u{1F984}", "unicorn"),
+    ("\u{1F436}", "dog"),
+    ("\u{1F431}", "cat"),
+    ("\u{1F441}", "eye"),
+    ("\u{2602}\u{2614}", "umbrella"),
+    ("\u{2764}", "heart"),
+    ("\u{2B50}", "star"),
+    ("\u{1F525}", "fire"),
+    ("\u{1F680}", "rocket"),
+    ("\u{1F308}", "rainbow"),
+    ("\u{1F4A1}", "light bulb"),
+    ("\u{1F4E7}", "e-mail"),
+    ("\u{2709}", "envelope"),
+    ("\u{1F3E0}", "house"),
+    ("\u{1F389}", "party popper"),
+    ("\u{1F44D}", "thumbs up"),
+    ("\u{1F44E}", "thumbs down"),
+    ("\u{1F600}", "grinning face"),
+    ("\u{1F602}", "face with tears of joy"),
+    ("\u{1F609}", "winking face"),
+    ("\u{2705}", "check mark"),
+    ("\u{274C}", "cross mark"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spoken_test() {
+        let symbols = Symbols::new();
+
+        assert_eq!(Some("alpha"), symbols.spoken('\u{03B1}'));
+        assert_eq!(Some("alpha"), symbols.spoken('\u{0391}'));
+        assert_eq!(Some("sigma"), symbols.spoken('\u{03C2}'));
+        assert_eq!(Some("infinity"), symbols.spoken('\u{221E}'));
+        assert_eq!(Some("at"), symbols.spoken('@'));
+        assert_eq!(Some("apple"), symbols.spoken('\u{1F34E}'));
+        assert_eq!(None, symbols.spoken('a'));
+        assert_eq!(None, symbols.spoken('$'));
+        assert_eq!(None, Symbols::empty().spoken('@'));
+    }
+
+    #[test]
+    fn add_and_remove_test() {
+        let mut symbols = Symbols::new();
+
+        symbols.add('#', "number");
+        symbols.add('\u{2603}', "snowman");
+        assert_eq!(Some("number"), symbols.spoken('#'));
+        assert_eq!(Some("snowman"), symbols.spoken('\u{2603}'));
+
+        assert!(symbols.remove('@'));
+        assert!(!symbols.remove('@'));
+        assert!(symbols.remove('\u{2603}'));
+        assert_eq!(None, symbols.spoken('@'));
+        assert_eq!(None, symbols.spoken('\u{2603}'));
+
+        symbols.add('@', "at sign");
+        assert_eq!(Some("at sign"), symbols.spoken('@'));
+    }
+
+    #[test]
+    fn find_test() {
+        let symbols = Symbols::new();
+
+        assert_eq!(Some(('@', "at")), symbols.find("@mention"));
+        assert_eq!(Some(('\u{03B1}', "alpha")), symbols.find("\u{03B1}"));
+        assert_eq!(Some(('\u{03B1}', "alpha")), symbols.find("\u{03B1}2"));
+        // a Greek word
+        assert_eq!(None, symbols.find("\u{03B1}\u{03B2}\u{03B3}"));
+        assert_eq!(None, symbols.find("apple"));
+        assert_eq!(None, symbols.find(""));
+    }
+}
@@ -0,0 +1,608 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
+use crate::core_is_an;
+use crate::core_is_an::Is;
+use crate::options::{AllCapsMode, Options};
+use crate::resolver::Resolver;
+use crate::rules::rule_acronyms;
+use crate::string_helper;
+
+/// A wrong indefinite article, found when scanning some text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finding {
+    /// The byte range of the article within the text.
+    pub span: Range<usize>,
+    /// The line of the article (starting at 1).
+    pub line: usize,
+    /// The column of the article, counted in characters (starting at 1).
+    pub column: usize,
+    /// The article as it appears in the text, for example "a" or "An".
+    pub found: String,
+    /// The article that should be used instead.
+    pub expected: &'static str,
+}
+
+struct Token<'t> {
+    text: &'t str,
+    start: usize,
+    line: usize,
+    column: usize,
+}
+
+pub fn check_text(text: &str, resolver: &Resolver, options: &Options) -> Vec<Finding> {
+    let tokens = tokenize(text);
+    let code = code_spans(text);
+
+    let shouted_options = Options {
+        all_caps: AllCapsMode::Words,
+        ..options.clone()
+    };
+
+    let mut findings = Vec::new();
+
+    for (index, token) in tokens.iter().enumerate() {
+        let next = match tokens.get(index + 1) {
+            Some(next) => next,
+            None => break,
+        };
+
+        let leading = token.text.len() - trim_opening(token.text).len();
+        let article = trim_closing_markup(&token.text[leading..]);
+        if !is_article(article) {
+            continue;
+        }
+
+        let previous = if index > 0 {
+            Some(&tokens[index - 1])
+        } else {
+            None
+        };
+        // quoted as code, as it is: '`an`', or in a fenced code block
+        let start = token.start + leading;
+        if code.iter().any(|span| span.contains(&start)) {
+            continue;
+        }
+
+        let is_quoted = token.text[..leading].ends_with(is_quote);
+        if !is_quoted && is_letter_name(article, token, previous, next.text) {
+            continue;
+        }
+
+        // a letter or a variable, followed by a function word or a verb: 'the letter a is a vowel', 'type a or b'
+        if is_function_word(trim_closing(next.text)) {
+            continue;
+        }
+
+        let following = next.text;
+
+        let is_shouted_sentence =
+            options.all_caps == AllCapsMode::Auto && is_shouted(&tokens, index);
+        let options = if is_shouted_sentence {
+            &shouted_options
+        } else {
+            options
+        };
+
+        let accepted = core_is_an::accepted(following, resolver, options);
+        if accepted.preferred == Is::None || accepted.accepts(article_is(article)) {
+            continue;
+        }
+
+        // 'A UMBRELLA' -> 'AN UMBRELLA', when the article and the word are both in ALL CAPS, or the whole sentence is
+        let is_article_upper_case = article.chars().all(|ch| ch.is_uppercase());
+        let head = &following[string_helper::get_first_word_span(following, resolver.symbols())];
+        let is_head_upper_case =
+            is_shouted_sentence || rule_acronyms::is_read_as_word(head, options);
+        let casing = if is_article_upper_case && is_head_upper_case {
+            head
+        } else {
+            article
+        };
+
+        findings.push(Finding {
+            span: start..start + article.len(),
+            line: token.line,
+            column: token.column + token.text[..leading].chars().count(),
+            found: article.to_string(),
+            expected: core_is_an::a_or_an_capitalized_to_match(accepted.preferred, casing, true),
+        });
+    }
+
+    findings
+}
+
+pub fn fix_text<'t>(text: &'t str, resolver: &Resolver, options: &Options) -> Cow<'t, str> {
+    let findings = check_text(text, resolver, options);
+    if findings.is_empty() {
+        return Cow::Borrowed(text);
+    }
+
+    let mut fixed = String::with_capacity(text.len() + findings.len());
+    let mut end = 0;
+
+    for finding in &findings {
+        fixed.push_str(&text[end..finding.span.start]);
+        fixed.push_str(finding.expected);
+        end = finding.span.end;
+    }
+
+    fixed.push_str(&text[end..]);
+
+    Cow::Owned(fixed)
+}
+
+fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+
+    let mut line = 1;
+    let mut column = 1;
+    let mut current: Option<(usize, usize, usize)> = None;
+
+    for (offset, ch) in text.char_indices() {
+        if ch.is_whitespace() || string_helper::is_zero_width(ch) {
+            if let Some((start, start_line, start_column)) = current.take() {
+                tokens.push(Token {
+                    text: &text[start..offset],
+                    start,
+                    line: start_line,
+                    column: start_column,
+                });
+            }
+        } else if current.is_none() {
+            current = Some((offset, line, column));
+        }
+
+        if ch == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+
+    if let Some((start, start_line, start_column)) = current {
+        tokens.push(Token {
+            text: &text[start..],
+            start,
+            line: start_line,
+            column: start_column,
+        });
+    }
+
+    tokens
+}
+
+// The byte ranges of inline code ('`an`', '``a `b` c``') and of fenced code blocks ('```' or '~~~' lines)
+fn code_spans(text: &str) -> Vec<Range<usize>> {
+    let mut spans = Vec::new();
+
+    let mut fence: Option<(usize, &str)> = None;
+    let mut prose_start = 0;
+    let mut line_start = 0;
+
+    while line_start < text.len() {
+        let line_end = text[line_start..]
+            .find('\n')
+            .map(|end| line_start + end + 1)
+            .unwrap_or_else(|| text.len());
+        let line = text[line_start..line_end].trim_start();
+        let marker = ["```", "~~~"]
+            .iter()
+            .find(|marker| line.starts_with(*marker))
+            .cloned();
+
+        match (fence, marker) {
+            (Some((start, open)), Some(marker)) if open == marker => {
+                spans.push(start..line_end);
+                fence = None;
+                prose_start = line_end;
+            }
+            (None, Some(marker)) => {
+                push_inline_code_spans(text, prose_start..line_start, &mut spans);
+                fence = Some((line_start, marker));
+            }
+            _ => {}
+        }
+
+        line_start = line_end;
+    }
+
+    match fence {
+        // a fence that is never closed runs to the end of the text
+        Some((start, _)) => spans.push(start..text.len()),
+        None => push_inline_code_spans(text, prose_start..text.len(), &mut spans),
+    }
+
+    spans
+}
+
+// Inline code starts with a run of backticks, and ends with a run of the same length
+fn push_inline_code_spans(text: &str, prose: Range<usize>, spans: &mut Vec<Range<usize>>) {
+    let mut runs: Vec<Range<usize>> = Vec::new();
+    for (offset, byte) in text.as_bytes()[prose.clone()].iter().enumerate() {
+        let offset = prose.start + offset;
+        if *byte != b'`' {
+            continue;
+        }
+
+        match runs.last_mut() {
+            Some(run) if run.end == offset => run.end += 1,
+            _ => runs.push(offset..offset + 1),
+        }
+    }
+
+    let mut index = 0;
+    while index < runs.len() {
+        let open = &runs[index];
+        let close = runs[index + 1..]
+            .iter()
+            .position(|run| run.len() == open.len());
+
+        match close {
+            Some(close) => {
+                spans.push(open.start..runs[index + 1 + close].end);
+                index += close + 2;
+            }
+            // backticks that are never closed are just backticks
+            None => index += 1,
+        }
+    }
+}
+
+// A sentence (or a line) with at least 2 words, all in ALL CAPS: 'AN UMBRELLA FOR EVERY USER', 'AN UMBRELLA'
+fn is_shouted(tokens: &[Token], index: usize) -> bool {
+    let ends_sentence = |token: &Token| token.text.ends_with(|ch: char| ".!?".contains(ch));
+    let line = tokens[index].line;
+
+    let mut start = index;
+    while start > 0 && tokens[start - 1].line == line && !ends_sentence(&tokens[start - 1]) {
+        start -= 1;
+    }
+
+    let mut end = index;
+    while end + 1 < tokens.len() && tokens[end + 1].line == line && !ends_sentence(&tokens[end]) {
+        end += 1;
+    }
+
+    let words: Vec<&str> = tokens[start..=end]
+        .iter()
+        .map(|token| token.text)
+        .filter(|text| text.chars().any(|ch| ch.is_alphabetic()))
+        .collect();
+
+    words.len() >= 2
+        && words
+            .iter()
+            .all(|word| !word.chars().any(|ch| ch.is_lowercase()))
+}
+
+fn trim_opening(token: &str) -> &str {
+    token.trim_start_matches(|ch: char| !ch.is_alphanumeric())
+}
+
+// '*a*' or '_an_': inline markup around the article
+fn trim_closing_markup(token: &str) -> &str {
+    token.trim_end_matches(|ch: char| "*_`~".contains(ch))
+}
+
+fn trim_closing(token: &str) -> &str {
+    token.trim_end_matches(|ch: char| !ch.is_alphanumeric())
+}
+
+// Words that never follow an article, in lower case only, so that 'an AND gate' is still checked
+fn is_function_word(word: &str) -> bool {
+    const FUNCTION_WORDS: &[&str] = &[
+        "and", "are", "as", "be", "but", "can", "could", "did", "does", "equals", "has", "have",
+        "is", "may", "might", "must", "nor", "of", "or", "shall", "should", "than", "then", "to",
+        "was", "were", "will", "would",
+    ];
+
+    FUNCTION_WORDS.contains(&word)
+}
+
+fn is_article(token: &str) -> bool {
+    token.eq_ignore_ascii_case("a") || token.eq_ignore_ascii_case("an")
+}
+
+fn article_is(article: &str) -> Is {
+    if article.len() == 2 {
+        return Is::An;
+    }
+
+    Is::A
+}
+
+fn is_quote(ch: char) -> bool {
+    "\"'\u{2018}\u{201C}\u{00AB}".contains(ch)
+}
+
+// A capital 'A' in the middle of a sentence, before a lower case word, is most likely the letter: "Plan A is", "vitamin A and".
+// A sentence starts at the start of a line, after '.', '!', '?' or ':',
+// and after a heading, a list bullet, a block quote or a quote: '## A umbrella', '- A apple', '> A apple', '" A apple'.
+fn is_letter_name(article: &str, token: &Token, previous: Option<&Token>, next: &str) -> bool {
+    let starts_sentence = match previous {
+        None => true,
+        Some(previous) => {
+            previous.line != token.line
+                || previous.text.ends_with(|ch: char| ".!?:".contains(ch))
+                || previous
+                    .text
+                    .chars()
+                    .all(|ch| "#-*+>".contains(ch) || is_quote(ch))
+        }
+    };
+    let next_is_lower = next.chars().next().map_or(false, |ch| ch.is_lowercase());
+
+    article == "A" && !starts_sentence && next_is_lower
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::options::Options;
+
+    fn check_text(text: &str, options: &Options) -> Vec<Finding> {
+        super::check_text(text, &Resolver::default(), options)
+    }
+
+    fn fix_text<'t>(text: &'t str, options: &Options) -> Cow<'t, str> {
+        super::fix_text(text, &Resolver::default(), options)
+    }
+
+    #[test]
+    fn check_text_finds_wrong_articles() {
+        let findings = check_text("A apple and\nan pear, a umbrella.", &Options::default());
+
+        assert_eq!(3, findings.len());
+
+        assert_eq!(0..1, findings[0].span);
+        assert_eq!((1, 1), (findings[0].line, findings[0].column));
+        assert_eq!("A", findings[0].found);
+        assert_eq!("An", findings[0].expected);
+
+        assert_eq!(12..14, findings[1].span);
+        assert_eq!((2, 1), (findings[1].line, findings[1].column));
+        assert_eq!("an", findings[1].found);
+        assert_eq!("a", findings[1].expected);
+
+        assert_eq!(21..22, findings[2].span);
+        assert_eq!((2, 10), (findings[2].line, findings[2].column));
+        assert_eq!("an", findings[2].expected);
+    }
+
+    #[test]
+    fn check_text_ignores_correct_articles() {
+        let text = "An umbrella, a user and an hour. (an apple) \"a unicorn\"";
+
+        assert!(check_text(text, &Options::default()).is_empty());
+    }
+
+    #[test]
+    fn check_text_accepts_either_article_when_ambiguous() {
+        let text = "a historic day, an historic day, a 1800 and an 1800";
+
+        assert!(check_text(text, &Options::default()).is_empty());
+    }
+
+    #[test]
+    fn fix_text_numbers() {
+        let options = Options::default();
+
+        assert_eq!(
+            "an $8 fee, a -8 score and an 8% rise",
+            fix_text("a $8 fee, an -8 score and a 8% rise", &options)
+        );
+        assert_eq!(
+            "an '80s classic in an 1800s house",
+            fix_text("a '80s classic in a 1800s house", &options)
+        );
+        assert_eq!(
+            "an 8\u{2013}10 minute wait for an 11:30 meeting with an 8GB card",
+            fix_text(
+                "a 8\u{2013}10 minute wait for a 11:30 meeting with a 8GB card",
+                &options
+            )
+        );
+    }
+
+    #[test]
+    fn fix_text_technical_tokens() {
+        let options = Options::default();
+
+        assert_eq!(
+            "Open an https://example.com link, a www.example.com link or an x@y.com address.",
+            fix_text(
+                "Open a https://example.com link, an www.example.com link or a x@y.com address.",
+                &options
+            )
+        );
+        assert_eq!(
+            "Run an .exe file from a /usr/bin path, or a C:\\Tools folder.",
+            fix_text(
+                "Run a .exe file from an /usr/bin path, or an C:\\Tools folder.",
+                &options
+            )
+        );
+    }
+
+    #[test]
+    fn check_text_ignores_non_articles() {
+        let text = "Plan A is a plan. Vitamin A and anything. a, an. Add a";
+
+        assert!(check_text(text, &Options::default()).is_empty());
+    }
+
+    #[test]
+    fn check_text_matches_upper_case_articles() {
+        let findings = check_text("AN UNICORN", &Options::default());
+
+        assert_eq!(1, findings.len());
+        assert_eq!("AN", findings[0].found);
+        assert_eq!("A", findings[0].expected);
+    }
+
+    #[test]
+    fn fix_text_preserves_case() {
+        let options = Options::default();
+
+        assert_eq!("An apple", fix_text("A apple", &options));
+        assert_eq!("A UNICORN", fix_text("AN UNICORN", &options));
+        assert_eq!(
+            "(an apple) and a pear\nor an hour.",
+            fix_text("(a apple) and an pear\nor a hour.", &options)
+        );
+    }
+
+    #[test]
+    fn fix_text_all_caps_sentences() {
+        let options = Options::with_all_caps(AllCapsMode::Auto);
+
+        assert_eq!(
+            "AN UMBRELLA FOR EVERY USER",
+            fix_text("A UMBRELLA FOR EVERY USER", &options)
+        );
+        assert_eq!(
+            "A USER WITH AN UMBRELLA.",
+            fix_text("AN USER WITH A UMBRELLA.", &options)
+        );
+        // not a sentence in ALL CAPS, but UMBRELLA looks like a word, and FBI like an initialism
+        assert_eq!(
+            "Take an UMBRELLA with you.",
+            fix_text("Take a UMBRELLA with you.", &options)
+        );
+        assert_eq!(
+            "Call an FBI agent.",
+            fix_text("Call a FBI agent.", &options)
+        );
+        // words that look like initialisms are still acronyms
+        assert_eq!(
+            "AN FBI AGENT ARRIVED TODAY",
+            fix_text("AN FBI AGENT ARRIVED TODAY", &options)
+        );
+        assert_eq!(
+            "AN HTML PAGE AND AN OWL",
+            fix_text("A HTML PAGE AND A OWL", &options)
+        );
+        // a heading of 2 words
+        assert_eq!("AN UMBRELLA", fix_text("AN UMBRELLA", &options));
+        assert_eq!("# AN UMBRELLA", fix_text("# A UMBRELLA", &options));
+        // by default, ALL CAPS words are acronyms
+        assert_eq!(
+            "A UMBRELLA FOR EVERY USER",
+            fix_text("AN UMBRELLA FOR EVERY USER", &Options::default())
+        );
+    }
+
+    #[test]
+    fn fix_text_borrows_when_nothing_is_wrong() {
+        let text = "An umbrella for a user.";
+
+        match fix_text(text, &Options::default()) {
+            Cow::Borrowed(fixed) => assert_eq!(text, fixed),
+            Cow::Owned(_) => panic!("expected the text to be borrowed"),
+        }
+    }
+
+    #[test]
+    fn fix_text_markup() {
+        let options = Options::default();
+
+        assert_eq!(
+            "an *hour*, an \"umbrella\" and an <b>apple</b>",
+            fix_text("a *hour*, a \"umbrella\" and a <b>apple</b>", &options)
+        );
+        assert_eq!("*an* hour", fix_text("*a* hour", &options));
+        assert_eq!("an\u{00A0}hour", fix_text("a\u{00A0}hour", &options));
+    }
+
+    #[test]
+    fn fix_text_code() {
+        let options = Options::default();
+
+        // inline code is quoted as it is
+        assert_eq!("an `an` there", fix_text("an `an` there", &options));
+        assert_eq!("an `a hour`", fix_text("a `a hour`", &options));
+        assert_eq!("``a `b` a hour``", fix_text("``a `b` a hour``", &options));
+        assert_eq!(
+            "an `Option` and an hour",
+            fix_text("a `Option` and a hour", &options)
+        );
+        // a backtick that is never closed
+        assert_eq!("a ` an hour", fix_text("a ` a hour", &options));
+
+        // so are fenced code blocks
+        let text = "A example:\n\n```rust\nlet a = a apple;\n```\n\n~~~\na hour\n~~~\nA example";
+        assert_eq!(
+            "An example:\n\n```rust\nlet a = a apple;\n```\n\n~~~\na hour\n~~~\nAn example",
+            fix_text(text, &options)
+        );
+        // a fenced code block that is never closed runs to the end
+        assert_eq!("```\na hour", fix_text("```\na hour", &options));
+    }
+
+    #[test]
+    fn fix_text_sentence_starts() {
+        let options = Options::default();
+
+        assert_eq!(
+            "An umbrella\nAn apple",
+            fix_text("A umbrella\nA apple", &options)
+        );
+        assert_eq!("Intro\n\nAn apple", fix_text("Intro\n\nA apple", &options));
+        assert_eq!("- An apple", fix_text("- A apple", &options));
+        assert_eq!("* An apple", fix_text("* A apple", &options));
+        assert_eq!("## An umbrella", fix_text("## A umbrella", &options));
+        assert_eq!("> An apple", fix_text("> A apple", &options));
+        assert_eq!(
+            "He said, \"An apple\"",
+            fix_text("He said, \"A apple\"", &options)
+        );
+        assert_eq!(
+            "He said, \u{201C}An apple\u{201D}",
+            fix_text("He said, \u{201C}A apple\u{201D}", &options)
+        );
+        // still the letter, in the middle of a sentence
+        assert_eq!("Plan A is ready", fix_text("Plan A is ready", &options));
+    }
+
+    #[test]
+    fn fix_text_letters_and_variables() {
+        let options = Options::default();
+
+        for text in &[
+            "The letter a is a vowel",
+            "Type a or b",
+            "The value of a is 5",
+            "A is the first letter.",
+            "Let a and b be numbers",
+            "The letter \"a\" is a vowel",
+            "Press 'a' to continue",
+            "Use (a) or (b)",
+        ] {
+            assert_eq!(Vec::<Finding>::new(), check_text(text, &options));
+            assert_eq!(*text, fix_text(text, &options));
+        }
+
+        // upper case words are still checked: 'an AND gate'
+        assert_eq!("an AND gate", fix_text("a AND gate", &options));
+    }
+
+    #[test]
+    fn fix_text_zero_width_characters() {
+        let options = Options::default();
+
+        assert_eq!("an\u{200B}hour", fix_text("a\u{200B}hour", &options));
+        assert_eq!("an\u{2060}apple", fix_text("a\u{2060}apple", &options));
+    }
+
+    #[test]
+    fn check_text_columns_count_characters() {
+        let findings = check_text("café (a apple)", &Options::default());
+
+        assert_eq!(1, findings.len());
+        assert_eq!(7..8, findings[0].span);
+        assert_eq!(7, findings[0].column);
+    }
+}
@@ -1 +1,2 @@
 pub mod string_helper;
+pub mod token_classifier;
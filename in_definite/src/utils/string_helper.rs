@@ -1,18 +1,252 @@
-pub fn get_first_word(word: &str) -> &str {
-    let word = word.trim();
+use std::borrow::Cow;
+use std::ops::Range;
 
-    let words: Vec<&str> = word.split(|c: char| " ,.-;:'".contains(c)).collect();
+use crate::rules::rule_technical;
+use crate::symbols::Symbols;
 
-    words[0]
+// The byte range of the head token.
+// Opening punctuation, quotes, brackets, inline markup like '*', '_' or '`', HTML tags and whitespace are skipped.
+// A URL, an e-mail address, a domain name, a file extension or a path is a single token: 'www.example.com', '/etc'
+pub fn get_first_word_span(word: &str, symbols: &Symbols) -> Range<usize> {
+    let start = match find_word_start(word, symbols) {
+        Some(start) => start,
+        None => return word.len()..word.len(),
+    };
+
+    if let Some(len) = rule_technical::technical_token_len(&word[start..]) {
+        return start..start + len;
+    }
+
+    let end = start + word_end(&word[start..]);
+
+    // keep an apostrophe just before a year: ''80s', ''18'
+    // or a sign or a currency symbol just before a number: '-8', '$8'
+    let mut prefix_start = start;
+    if word[start..].starts_with(|c: char| c.is_numeric()) {
+        let before = word[..start].char_indices().last();
+        if let Some((offset, _)) = before.filter(|(_, c)| is_apostrophe(*c)) {
+            return offset..end;
+        }
+
+        for (offset, c) in word[..start].char_indices().rev() {
+            if !is_sign(c) && !is_currency_symbol(c) {
+                break;
+            }
+
+            prefix_start = offset;
+        }
+    }
+
+    prefix_start..end
+}
+
+// '<b>hour</b>': the start of a tag is skipped, up to its end
+// A symbol that is read out by name starts a word: '∞ loop'
+// So does the punctuation at the start of a path or a file extension: '/etc', '.exe'
+fn find_word_start(word: &str, symbols: &Symbols) -> Option<usize> {
+    let mut offset = 0;
+
+    while let Some(c) = word[offset..].chars().next() {
+        if c.is_alphanumeric() || symbols.spoken(c).is_some() {
+            return Some(offset);
+        }
+
+        if "./\\~".contains(c) && rule_technical::technical_token_len(&word[offset..]).is_some() {
+            return Some(offset);
+        }
+
+        if let Some(tag_end) = html_tag_end(&word[offset..]) {
+            offset += tag_end;
+            continue;
+        }
+
+        offset += c.len_utf8();
+    }
+
+    None
+}
+
+fn html_tag_end(text: &str) -> Option<usize> {
+    let mut chars = text.chars();
+    if chars.next() != Some('<')
+        || !chars
+            .next()
+            .map_or(false, |c| c == '/' || c.is_ascii_alphabetic())
+    {
+        return None;
+    }
+
+    text.find('>').map(|end| end + 1)
+}
+
+// Zero-width spaces and the byte order mark split words, like whitespace.
+pub fn is_zero_width(c: char) -> bool {
+    "\u{200B}\u{2060}\u{FEFF}".contains(c)
+}
+
+// a group mark or a decimal mark between digits is part of a number: '18,000', '1.8', "8'000"
+// as is the mark of a fraction, a range or a time: '1/8', '8-10', '8:30'
+fn word_end(rest: &str) -> usize {
+    let mut previous = None;
+    let mut chars = rest.char_indices().peekable();
+
+    while let Some((offset, c)) = chars.next() {
+        let is_in_number = (is_number_mark(c) || "/-\u{2013}:".contains(c))
+            && previous.map_or(false, |p: char| p.is_numeric())
+            && chars.peek().map_or(false, |(_, next)| next.is_numeric());
+
+        if !is_in_number && (c.is_whitespace() || is_zero_width(c) || is_closing(c)) {
+            return offset;
+        }
+
+        previous = Some(c);
+    }
+
+    rest.len()
+}
+
+// Closing punctuation, quotes, brackets and inline markup: 'hour*', 'hour_', 'hour`', 'hour</b>'
+fn is_closing(c: char) -> bool {
+    ",.-;:'!?)]}>\"\u{2019}\u{201D}*_`~<|".contains(c)
 }
 
-pub fn get_first_letter(word: &str) -> char {
-    word.chars().next().unwrap()
+// A mark between the digits of a number: ',' and '.' for groups or a decimal point, "'" (Swiss) or a no-break space (French).
+pub fn is_number_mark(c: char) -> bool {
+    ",.'\u{2019}\u{00A0}\u{202F}\u{2009}".contains(c)
 }
 
+pub fn is_apostrophe(c: char) -> bool {
+    "'\u{2018}\u{2019}".contains(c)
+}
+
+pub fn is_sign(c: char) -> bool {
+    "+-\u{2212}\u{00B1}".contains(c)
+}
+
+pub fn is_currency_symbol(c: char) -> bool {
+    "$\u{00A2}\u{00A3}\u{00A5}\u{20A1}\u{20A6}\u{20A9}\u{20AA}\u{20AB}\u{20AC}\u{20AD}\u{20B1}\u{20B2}\u{20B4}\u{20B5}\u{20B8}\u{20B9}\u{20BA}\u{20BD}\u{0E3F}"
+        .contains(c)
+}
+
+// Accented Latin letters and ligatures, with the letters they are folded to: 'é' to 'e', 'œ' to 'oe'.
+const FOLDED_LETTERS: &[(&str, &str)] = &[
+    (
+        "\u{00C0}\u{00C1}\u{00C2}\u{00C3}\u{00C4}\u{00C5}\u{0100}\u{0102}\u{0104}",
+        "A",
+    ),
+    (
+        "\u{00E0}\u{00E1}\u{00E2}\u{00E3}\u{00E4}\u{00E5}\u{0101}\u{0103}\u{0105}",
+        "a",
+    ),
+    ("\u{00C6}", "AE"),
+    ("\u{00E6}", "ae"),
+    ("\u{00C7}\u{0106}\u{0108}\u{010A}\u{010C}", "C"),
+    ("\u{00E7}\u{0107}\u{0109}\u{010B}\u{010D}", "c"),
+    ("\u{010E}\u{0110}", "D"),
+    ("\u{010F}\u{0111}", "d"),
+    (
+        "\u{00C8}\u{00C9}\u{00CA}\u{00CB}\u{0112}\u{0114}\u{0116}\u{0118}\u{011A}",
+        "E",
+    ),
+    (
+        "\u{00E8}\u{00E9}\u{00EA}\u{00EB}\u{0113}\u{0115}\u{0117}\u{0119}\u{011B}",
+        "e",
+    ),
+    ("\u{011C}\u{011E}\u{0120}\u{0122}", "G"),
+    ("\u{011D}\u{011F}\u{0121}\u{0123}", "g"),
+    ("\u{0124}\u{0126}", "H"),
+    ("\u{0125}\u{0127}", "h"),
+    (
+        "\u{00CC}\u{00CD}\u{00CE}\u{00CF}\u{0128}\u{012A}\u{012C}\u{012E}\u{0130}",
+        "I",
+    ),
+    (
+        "\u{00EC}\u{00ED}\u{00EE}\u{00EF}\u{0129}\u{012B}\u{012D}\u{012F}\u{0131}",
+        "i",
+    ),
+    ("\u{0134}", "J"),
+    ("\u{0135}", "j"),
+    ("\u{0136}", "K"),
+    ("\u{0137}", "k"),
+    ("\u{0139}\u{013B}\u{013D}\u{013F}\u{0141}", "L"),
+    ("\u{013A}\u{013C}\u{013E}\u{0140}\u{0142}", "l"),
+    ("\u{00D1}\u{0143}\u{0145}\u{0147}", "N"),
+    ("\u{00F1}\u{0144}\u{0146}\u{0148}", "n"),
+    (
+        "\u{00D2}\u{00D3}\u{00D4}\u{00D5}\u{00D6}\u{00D8}\u{014C}\u{014E}\u{0150}",
+        "O",
+    ),
+    (
+        "\u{00F2}\u{00F3}\u{00F4}\u{00F5}\u{00F6}\u{00F8}\u{014D}\u{014F}\u{0151}",
+        "o",
+    ),
+    ("\u{0152}", "OE"),
+    ("\u{0153}", "oe"),
+    ("\u{0154}\u{0156}\u{0158}", "R"),
+    ("\u{0155}\u{0157}\u{0159}", "r"),
+    ("\u{015A}\u{015C}\u{015E}\u{0160}", "S"),
+    ("\u{015B}\u{015D}\u{015F}\u{0161}", "s"),
+    ("\u{00DF}", "ss"),
+    ("\u{0162}\u{0164}\u{0166}", "T"),
+    ("\u{0163}\u{0165}\u{0167}", "t"),
+    (
+        "\u{00D9}\u{00DA}\u{00DB}\u{00DC}\u{0168}\u{016A}\u{016C}\u{016E}\u{0170}\u{0172}",
+        "U",
+    ),
+    (
+        "\u{00F9}\u{00FA}\u{00FB}\u{00FC}\u{0169}\u{016B}\u{016D}\u{016F}\u{0171}\u{0173}",
+        "u",
+    ),
+    ("\u{0174}", "W"),
+    ("\u{0175}", "w"),
+    ("\u{00DD}\u{0176}\u{0178}", "Y"),
+    ("\u{00FD}\u{00FF}\u{0177}", "y"),
+    ("\u{0179}\u{017B}\u{017D}", "Z"),
+    ("\u{017A}\u{017C}\u{017E}", "z"),
+];
+
+fn is_combining_mark(c: char) -> bool {
+    ('\u{0300}'..='\u{036F}').contains(&c)
+}
+
+// 'élan' to 'elan', 'œuvre' to 'oeuvre'. Combining accents are dropped, so a decomposed (NFD) word folds the same as a composed (NFC) word.
+pub fn fold_accents(word: &str) -> Cow<'_, str> {
+    if word.is_ascii() {
+        return Cow::Borrowed(word);
+    }
+
+    let mut folded = String::with_capacity(word.len());
+    for c in word.chars().filter(|c| !is_combining_mark(*c)) {
+        match FOLDED_LETTERS
+            .iter()
+            .find(|(letters, _)| letters.contains(c))
+        {
+            Some((_, base)) => folded.push_str(base),
+            None => folded.push(c),
+        }
+    }
+
+    Cow::Owned(folded)
+}
+
+pub fn get_first_letter(word: &str) -> Option<char> {
+    word.chars().next()
+}
+
+// A combining accent does not change the case: 'A\u{030A}ngstro\u{0308}m' is title case
 pub fn is_title_case(first_word: &str) -> bool {
-    let mut chars = first_word.chars();
-    chars.next().unwrap().is_uppercase() && chars.all(|ch| ch.is_lowercase())
+    let mut chars = first_word.chars().filter(|ch| !is_combining_mark(*ch));
+    chars.next().map_or(false, |ch| ch.is_uppercase()) && chars.all(|ch| ch.is_lowercase())
+}
+
+pub fn is_latin_letter(ch: char) -> bool {
+    ch.is_ascii_alphabetic()
+        || (ch.is_alphabetic()
+            && (('\u{00C0}'..='\u{024F}').contains(&ch) || ('\u{1E00}'..='\u{1EFF}').contains(&ch)))
+}
+
+pub fn is_upper_case(word: &str) -> bool {
+    word.chars().count() > 1 && word.chars().all(|ch| ch.is_uppercase())
 }
 
 pub fn strip_end<'s>(word: &'s str, ending: &str) -> &'s str {
@@ -30,20 +264,133 @@ pub fn strip_end<'s>(word: &'s str, ending: &str) -> &'s str {
 mod tests {
     use super::*;
 
+    fn get_first_word(word: &str) -> &str {
+        &word[get_first_word_span(word, &Symbols::new())]
+    }
+
+    #[test]
+    fn fold_accents_test() {
+        assert_eq!("elan", fold_accents("\u{00E9}lan"));
+        assert_eq!("elan", fold_accents("e\u{0301}lan"));
+        assert_eq!("uber", fold_accents("\u{00FC}ber"));
+        assert_eq!("Angstrom", fold_accents("\u{00C5}ngstr\u{00F6}m"));
+        assert_eq!("Angstrom", fold_accents("A\u{030A}ngstro\u{0308}m"));
+        assert_eq!("oeuvre", fold_accents("\u{0153}uvre"));
+        assert_eq!("aesthetic", fold_accents("\u{00E6}sthetic"));
+        assert_eq!("apple", fold_accents("apple"));
+        assert_eq!("\u{044F}\u{0431}", fold_accents("\u{044F}\u{0431}"));
+    }
+
+    #[test]
+    fn get_first_word_span_test() {
+        assert_eq!(1..5, get_first_word_span("(hour)", &Symbols::new()));
+        assert_eq!(3..7, get_first_word_span("<b>hour</b>", &Symbols::new()));
+        assert_eq!(2..6, get_first_word_span("**'80s", &Symbols::new()));
+        assert_eq!(0..2, get_first_word_span("$8", &Symbols::new()));
+        assert_eq!(3..3, get_first_word_span("(*)", &Symbols::new()));
+        assert_eq!(0..0, get_first_word_span("", &Symbols::new()));
+        assert_eq!(1..4, get_first_word_span("(@me)", &Symbols::new()));
+        assert_eq!(2..4, get_first_word_span("(@me)", &Symbols::empty()));
+    }
+
     #[test]
     fn get_first_word_test() {
         assert_eq!("one", get_first_word("one two"));
         assert_eq!("one", get_first_word("one two three"));
         assert_eq!("one", get_first_word("one-two three"));
         assert_eq!("heir", get_first_word("heir's"));
+        // leading punctuation is skipped
+        assert_eq!("8", get_first_word(".8"));
+        assert_eq!("80,000,000", get_first_word("80,000,000,"));
+        assert_eq!("1.8", get_first_word("1.8."));
+        assert_eq!("8'000", get_first_word("8'000"));
+        assert_eq!("18\u{202F}000", get_first_word("18\u{202F}000 rise"));
+        assert_eq!("80", get_first_word("80's"));
+        assert_eq!("1/8", get_first_word("1/8 inch"));
+        assert_eq!("8-10", get_first_word("8-10-minute"));
+        assert_eq!("8:30", get_first_word("8:30,"));
+        assert_eq!("2", get_first_word("2-year-old"));
+        assert_eq!("apple", get_first_word("apple,8"));
+        assert_eq!("apple", get_first_word("-apple"));
+        assert_eq!("'80s", get_first_word("'80s"));
+        assert_eq!("\u{2019}18", get_first_word("(\u{2019}18 vintage)"));
+        assert_eq!("'80s", get_first_word("('80s"));
+        assert_eq!("umbrella", get_first_word("(umbrella"));
+        // quotes, brackets and markup
+        assert_eq!("umbrella", get_first_word("\"umbrella\""));
+        assert_eq!("umbrella", get_first_word("\u{201C}umbrella\u{201D}"));
+        assert_eq!("apple", get_first_word("(apple)"));
+        assert_eq!("apple", get_first_word("[apple]"));
+        assert_eq!("hour", get_first_word("*hour*"));
+        assert_eq!("hour", get_first_word("**hour**"));
+        assert_eq!("hour", get_first_word("_hour_"));
+        assert_eq!("hour", get_first_word("`hour`"));
+        assert_eq!("hour", get_first_word("~~hour~~"));
+        assert_eq!("hour", get_first_word("<b>hour</b>"));
+        assert_eq!("hour", get_first_word("<em class=\"x\">hour"));
+        // whitespace and zero-width characters
+        assert_eq!("hour", get_first_word("\t\nhour\tly"));
+        assert_eq!("hour", get_first_word("\u{00A0}hour\u{00A0}long"));
+        assert_eq!("hour", get_first_word("\u{200B}hour\u{200B}ly"));
+        assert_eq!("hour", get_first_word("\u{FEFF}hour"));
+        // symbols that are read out by name
+        assert_eq!("\u{221E}", get_first_word("\u{221E} loop"));
+        assert_eq!("\u{03B1}", get_first_word("\u{03B1}-helix"));
+        assert_eq!("\u{221A}2", get_first_word("(\u{221A}2)"));
+        assert_eq!("@mention", get_first_word("@mention"));
+        assert_eq!("#hashtag", get_first_word("#hashtag!"));
+        assert_eq!("&amp", get_first_word("&amp;"));
+        assert_eq!("+1", get_first_word("+1"));
+        assert_eq!("\u{1F34E}", get_first_word("\u{1F34E} a day"));
+        assert_eq!("", get_first_word("..."));
+        // URLs, e-mail addresses, domain names, file extensions and paths
+        assert_eq!(
+            "https://example.com",
+            get_first_word("(https://example.com)")
+        );
+        assert_eq!("www.example.com", get_first_word("www.example.com."));
+        assert_eq!("x@y.com", get_first_word("x@y.com address"));
+        assert_eq!("/etc", get_first_word("/etc path"));
+        assert_eq!(".exe", get_first_word("*.exe* file"));
+        assert_eq!("C:\\Windows", get_first_word("C:\\Windows folder"));
+        assert_eq!("readme", get_first_word("readme.txt"));
+        // signs and currency symbols before a number are kept
+        assert_eq!("-8", get_first_word("-8"));
+        assert_eq!("$8", get_first_word("$8 fee"));
+        assert_eq!("-$8", get_first_word("(-$8)"));
+        assert_eq!("€11", get_first_word("€11-euro"));
+        assert_eq!("8", get_first_word("- 8"));
     }
 
     #[test]
+    fn is_latin_letter_test() {
+        assert!(is_latin_letter('a'));
+        assert!(is_latin_letter('Z'));
+        assert!(is_latin_letter('é'));
+        assert!(is_latin_letter('Œ'));
+        assert!(!is_latin_letter('8'));
+        assert!(!is_latin_letter('×'));
+        assert!(!is_latin_letter('я'));
+        assert!(!is_latin_letter('中'));
+    }
+
+    #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn is_title_case_test() {
         assert_eq!(false, is_title_case("one"));
         assert_eq!(true, is_title_case("Two"));
         assert_eq!(false, is_title_case("THree"));
         assert_eq!(false, is_title_case("FOUR"));
+        assert_eq!(false, is_title_case(""));
+    }
+
+    #[test]
+    fn is_upper_case_test() {
+        assert!(is_upper_case("AN"));
+        assert!(is_upper_case("UNICORN"));
+        assert!(!is_upper_case("A"));
+        assert!(!is_upper_case("An"));
+        assert!(!is_upper_case("an"));
     }
 
     #[test]
@@ -0,0 +1,110 @@
+use crate::string_helper;
+
+/// How the start of a head token is read out.
+#[derive(Debug, PartialEq)]
+pub enum TokenClass<'w> {
+    /// A number: the leading digits, with any separators or decimal point, for example '4' for '4K', '18' for '18th' or '1.5' for '1.5GB'.
+    Number(&'w str),
+    /// Letters read out one by one: for example 'MP' for 'MP3', 'HTML' for 'HTMLElement' or 'm' for 'mRNA'.
+    Letters(&'w str),
+    /// A word, read as normal.
+    Word,
+}
+
+pub fn classify(word: &str) -> TokenClass<'_> {
+    let first = match word.chars().next() {
+        Some(first) => first,
+        None => return TokenClass::Word,
+    };
+
+    if first.is_numeric() {
+        return TokenClass::Number(leading_number(word));
+    }
+
+    let upper = leading(word, |ch| ch.is_uppercase());
+    let rest = &word[upper.len()..];
+
+    // FBI, MP3, HTML5, F16
+    if rest.is_empty() || rest.starts_with(|ch: char| ch.is_numeric()) && !upper.is_empty() {
+        return TokenClass::Letters(upper);
+    }
+
+    // mRNA, pH, xUnit, iOS, eBay
+    let second = rest.chars().nth(1);
+    if first.is_lowercase() && second.map_or(false, |ch| ch.is_uppercase()) {
+        return TokenClass::Letters(&word[..first.len_utf8()]);
+    }
+
+    if upper.chars().count() >= 2 && rest.starts_with(|ch: char| ch.is_lowercase()) {
+        // plural: PDFs, FAQs
+        if rest == "s" {
+            return TokenClass::Letters(upper);
+        }
+
+        // the last capital letter starts the next word: HTMLElement, XMLHttpRequest, OAuth
+        let last = upper.chars().last().map_or(0, |ch| ch.len_utf8());
+        return TokenClass::Letters(&upper[..upper.len() - last]);
+    }
+
+    TokenClass::Word
+}
+
+// '18,000' or '1.5': a group mark or a decimal mark is part of the number only when a digit follows it
+fn leading_number(word: &str) -> &str {
+    let mut end = 0;
+    let mut chars = word.char_indices().peekable();
+
+    while let Some((offset, ch)) = chars.next() {
+        let is_separator = string_helper::is_number_mark(ch)
+            && chars.peek().map_or(false, |(_, next)| next.is_numeric());
+        if !ch.is_numeric() && !is_separator {
+            break;
+        }
+
+        end = offset + ch.len_utf8();
+    }
+
+    &word[..end]
+}
+
+fn leading<F: Fn(char) -> bool>(word: &str, predicate: F) -> &str {
+    let end = word
+        .char_indices()
+        .find(|(_, ch)| !predicate(*ch))
+        .map_or(word.len(), |(offset, _)| offset);
+
+    &word[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_test() {
+        // numbers
+        assert_eq!(TokenClass::Number("8"), classify("8"));
+        assert_eq!(TokenClass::Number("4"), classify("4K"));
+        assert_eq!(TokenClass::Number("18"), classify("18th"));
+        assert_eq!(TokenClass::Number("1.5"), classify("1.5GB"));
+        assert_eq!(TokenClass::Number("80,000"), classify("80,000,"));
+        assert_eq!(TokenClass::Number("8'000"), classify("8'000th"));
+        // letters
+        assert_eq!(TokenClass::Letters("FBI"), classify("FBI"));
+        assert_eq!(TokenClass::Letters("MP"), classify("MP3"));
+        assert_eq!(TokenClass::Letters("HTML"), classify("HTML5"));
+        assert_eq!(TokenClass::Letters("F"), classify("F16"));
+        assert_eq!(TokenClass::Letters("m"), classify("mRNA"));
+        assert_eq!(TokenClass::Letters("p"), classify("pH"));
+        assert_eq!(TokenClass::Letters("x"), classify("xUnit"));
+        assert_eq!(TokenClass::Letters("PDF"), classify("PDFs"));
+        assert_eq!(TokenClass::Letters("HTML"), classify("HTMLElement"));
+        assert_eq!(TokenClass::Letters("O"), classify("OAuth"));
+        // words
+        assert_eq!(TokenClass::Word, classify("umbrella"));
+        assert_eq!(TokenClass::Word, classify("Umbrella"));
+        assert_eq!(TokenClass::Word, classify("YouTube"));
+        assert_eq!(TokenClass::Word, classify("mp3"));
+        assert_eq!(TokenClass::Word, classify(""));
+    }
+}
@@ -3,8 +3,6 @@
 use std::env;
 use std::process;
 
-use in_definite;
-
 /// Get the indefinite article ('a' or 'an') to match the given word. For example: an umbrella, a user.
 fn main() {
     println!("= in_definite =");
@@ -36,6 +34,6 @@ impl Config {
             return Err(usage);
         }
         let word = args[1].clone();
-        return Ok(Config { word });
+        Ok(Config { word })
     }
 }